@@ -0,0 +1,143 @@
+use crate::error::DoccerError;
+use crate::renderer::OutputFormat;
+use crate::Cli;
+use clap::parser::ValueSource;
+use clap::{ArgMatches, ValueEnum};
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Config file looked up in the current directory when `--config` isn't given
+pub const DEFAULT_CONFIG_FILE: &str = "doccer.toml";
+
+/// Default options read from `doccer.toml`; keys are the long CLI flag names,
+/// e.g. `crate-version = "1.0"` or `group-by-kind = true`.
+///
+/// Only options that shape every run are accepted. Flags that pick the input
+/// (`crate-path`, `package`, the feature flags, `format-version`) or switch the
+/// run into another mode (`output-dir`, `stats`, `check`, `debug`, `dump-parsed`)
+/// stay on the command line, since a project-wide default for them would change
+/// what every plain `doccer <crate>` call does.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Config {
+    pub crate_version: Option<String>,
+    pub target: Option<String>,
+    pub toolchain: Option<String>,
+    /// Relative paths are resolved against the config file's directory
+    pub stdlib_json_path: Option<PathBuf>,
+    pub format: Option<String>,
+    pub compact: Option<bool>,
+    pub auto_traits: Option<bool>,
+    pub group_assoc: Option<bool>,
+    pub no_auto_comment: Option<bool>,
+    pub show_perf_attrs: Option<bool>,
+    pub sections: Option<Vec<String>>,
+    pub group_by_kind: Option<bool>,
+    pub wrap: Option<bool>,
+    pub width: Option<usize>,
+    pub inline_reexports: Option<bool>,
+    pub include_private: Option<bool>,
+    pub metadata: Option<bool>,
+    pub no_crate_header: Option<bool>,
+    pub qualify_external: Option<bool>,
+    pub include_tests: Option<bool>,
+    pub emit_signatures_only: Option<bool>,
+    pub show_impl_headers: Option<bool>,
+    pub show_inherited: Option<bool>,
+    pub since: Option<String>,
+    pub breadth_first: Option<bool>,
+    pub sort_impls: Option<bool>,
+    pub human: Option<bool>,
+    pub max_items: Option<usize>,
+    pub exclude: Option<Vec<String>>,
+    pub fail_on_unknown: Option<bool>,
+    /// Where the config was read from, for error messages
+    #[serde(skip)]
+    pub path: PathBuf,
+}
+
+impl Config {
+    /// Load `explicit`, or `doccer.toml` from the current directory if it exists
+    pub fn load(explicit: Option<&Path>) -> Result<Option<Self>, DoccerError> {
+        let path = match explicit {
+            Some(path) => path.to_path_buf(),
+            None => {
+                let path = PathBuf::from(DEFAULT_CONFIG_FILE);
+                if !path.is_file() {
+                    return Ok(None);
+                }
+                path
+            }
+        };
+
+        let content = fs::read_to_string(&path).map_err(|e| DoccerError::read_file(&path, e))?;
+        Self::parse(&content, &path).map(Some)
+    }
+
+    pub fn parse(content: &str, path: &Path) -> Result<Self, DoccerError> {
+        let mut config: Self = toml::from_str(content).map_err(|e| DoccerError::Config {
+            path: path.display().to_string(),
+            message: e.message().to_string(),
+        })?;
+        config.path = path.to_path_buf();
+        Ok(config)
+    }
+
+    /// Fill in every option that wasn't given on the command line
+    pub fn apply(self, cli: &mut Cli, matches: &ArgMatches) -> Result<(), DoccerError> {
+        let on_cli = |id: &str| matches.value_source(id) == Some(ValueSource::CommandLine);
+
+        let format = match self.format {
+            Some(name) => Some(OutputFormat::from_str(&name, true).map_err(|_| DoccerError::Config {
+                path: self.path.display().to_string(),
+                message: format!("unknown format '{}'", name),
+            })?),
+            None => None,
+        };
+
+        fill(&mut cli.crate_version, self.crate_version, on_cli("crate_version"));
+        fill(&mut cli.target, self.target, on_cli("target"));
+        fill(&mut cli.toolchain, self.toolchain.map(Some), on_cli("toolchain"));
+        let config_dir = self.path.parent().unwrap_or(Path::new(""));
+        let stdlib_json_path = self.stdlib_json_path.map(|path| Some(config_dir.join(path)));
+        fill(&mut cli.stdlib_json_path, stdlib_json_path, on_cli("stdlib_json_path"));
+        fill(&mut cli.format, format, on_cli("format"));
+        fill(&mut cli.compact, self.compact, on_cli("compact"));
+        fill(&mut cli.auto_traits, self.auto_traits, on_cli("auto_traits"));
+        fill(&mut cli.group_assoc, self.group_assoc, on_cli("group_assoc"));
+        fill(&mut cli.no_auto_comment, self.no_auto_comment, on_cli("no_auto_comment"));
+        fill(&mut cli.show_perf_attrs, self.show_perf_attrs, on_cli("show_perf_attrs"));
+        fill(&mut cli.sections, self.sections.map(Some), on_cli("sections"));
+        fill(&mut cli.group_by_kind, self.group_by_kind, on_cli("group_by_kind"));
+        fill(&mut cli.wrap, self.wrap, on_cli("wrap"));
+        fill(&mut cli.width, self.width.map(Some), on_cli("width"));
+        fill(&mut cli.inline_reexports, self.inline_reexports, on_cli("inline_reexports"));
+        // Like their flags, these only apply to local crates, so a project config
+        // that sets them leaves docs.rs and stdlib lookups alone
+        let local = cli.crate_path.is_some();
+        fill(&mut cli.include_private, self.include_private, on_cli("include_private") || !local);
+        fill(&mut cli.metadata, self.metadata, on_cli("metadata") || !local);
+        fill(&mut cli.no_crate_header, self.no_crate_header, on_cli("no_crate_header"));
+        fill(&mut cli.qualify_external, self.qualify_external, on_cli("qualify_external"));
+        fill(&mut cli.include_tests, self.include_tests, on_cli("include_tests"));
+        fill(&mut cli.emit_signatures_only, self.emit_signatures_only, on_cli("emit_signatures_only"));
+        fill(&mut cli.show_impl_headers, self.show_impl_headers, on_cli("show_impl_headers"));
+        fill(&mut cli.show_inherited, self.show_inherited, on_cli("show_inherited"));
+        fill(&mut cli.since, self.since.map(Some), on_cli("since"));
+        fill(&mut cli.breadth_first, self.breadth_first, on_cli("breadth_first"));
+        fill(&mut cli.sort_impls, self.sort_impls, on_cli("sort_impls"));
+        fill(&mut cli.max_items, self.max_items.map(Some), on_cli("max_items"));
+        // `--bytes` on the command line also overrides `human = true`
+        fill(&mut cli.human, self.human, on_cli("human") || on_cli("bytes"));
+        fill(&mut cli.exclude, self.exclude, on_cli("exclude"));
+        fill(&mut cli.fail_on_unknown, self.fail_on_unknown, on_cli("fail_on_unknown"));
+        Ok(())
+    }
+}
+
+fn fill<T>(slot: &mut T, value: Option<T>, on_cli: bool) {
+    if let (Some(value), false) = (value, on_cli) {
+        *slot = value;
+    }
+}
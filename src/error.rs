@@ -0,0 +1,180 @@
+use std::io;
+use std::path::Path;
+
+/// Failure modes of loading, fetching and narrowing rustdoc JSON
+#[derive(Debug, thiserror::Error)]
+pub enum DoccerError {
+    /// The request failed or docs.rs answered with an unexpected status
+    #[error("{message}")]
+    Network {
+        message: String,
+        #[source]
+        source: Option<reqwest::Error>,
+    },
+
+    /// docs.rs has no JSON documentation for this crate, version and target
+    #[error(
+        "Documentation not found for crate '{name}' version '{version}' on target '{target}'. \n\
+         This could be because:\n\
+         1. The crate doesn't exist\n\
+         2. The version doesn't exist\n\
+         3. The target isn't supported\n\
+         4. The crate version was published before May 23, 2025\n\n\
+         Note: docs.rs only generates JSON documentation for crates published after May 23, 2025.\n\
+         Try a newer version or try a different crate like 'clap' (4.3.0+) which has JSON documentation."
+    )]
+    NotFound {
+        name: String,
+        version: String,
+        target: String,
+    },
+
+    /// The crate version exists on docs.rs but was not built for the requested target
+    #[error(
+        "Crate '{name}' version '{version}' has no documentation built for target '{target}'.\n\
+         docs.rs built it for: {}",
+        available.join(", ")
+    )]
+    TargetNotBuilt {
+        name: String,
+        version: String,
+        target: String,
+        available: Vec<String>,
+    },
+
+    /// `latest` has no JSON docs and the only release that does is a pre-release
+    #[error(
+        "No stable release of '{name}' has JSON documentation on docs.rs; the newest release \
+         that does is the pre-release {version}.\n\
+         Pass --crate-version {version} to use it."
+    )]
+    PreReleaseOnly { name: String, version: String },
+
+    /// The content is not valid rustdoc JSON
+    #[error("{0}")]
+    Parse(String),
+
+    /// Reading or decompressing the documentation failed
+    #[error("{context}: {source}")]
+    Io {
+        context: String,
+        #[source]
+        source: io::Error,
+    },
+
+    /// The JSON was produced by a rustdoc with a different format version
+    #[error("{}", unsupported_format_message(*found, *expected))]
+    UnsupportedFormat { found: u64, expected: u64 },
+
+    /// The config file is not valid TOML or sets an unknown option
+    #[error("Invalid config file {path}: {message}")]
+    Config { path: String, message: String },
+
+    /// A segment of a requested module or item path does not exist
+    #[error("Item '{segment}' not found in the path '{path}'")]
+    ModuleNotFound { segment: String, path: String },
+
+    /// No standard library JSON at `path`; `crate_name` is set when the path came
+    /// from the rustup toolchain layout rather than `--stdlib-json-path`
+    #[error("{}", stdlib_not_found_message(path, crate_name.as_deref()))]
+    StdlibNotFound {
+        path: String,
+        crate_name: Option<String>,
+    },
+
+    /// Something doccer needs from the host is missing, e.g. a home directory
+    #[error("{0}")]
+    Environment(String),
+
+    /// The local crate or its manifest is missing, or rustdoc failed to document it
+    #[error("{0}")]
+    LocalCrate(String),
+
+    /// `--features` names features the manifest doesn't declare
+    #[error(
+        "Unknown feature(s) for {manifest}: {}\nAvailable features: {}",
+        unknown.join(", "),
+        if available.is_empty() { "(none declared)".to_string() } else { available.join(", ") }
+    )]
+    UnknownFeatures {
+        manifest: String,
+        unknown: Vec<String>,
+        available: Vec<String>,
+    },
+}
+
+impl DoccerError {
+    pub fn network(message: impl Into<String>, source: reqwest::Error) -> Self {
+        DoccerError::Network {
+            message: format!("{}: {}", message.into(), source),
+            source: Some(source),
+        }
+    }
+
+    pub fn io(context: impl Into<String>, source: io::Error) -> Self {
+        DoccerError::Io {
+            context: context.into(),
+            source,
+        }
+    }
+
+    pub fn read_file(path: &Path, source: io::Error) -> Self {
+        Self::io(format!("Failed to read file: {}", path.display()), source)
+    }
+}
+
+impl From<reqwest::Error> for DoccerError {
+    fn from(source: reqwest::Error) -> Self {
+        DoccerError::network("Request failed", source)
+    }
+}
+
+impl From<serde_json::Error> for DoccerError {
+    fn from(source: serde_json::Error) -> Self {
+        DoccerError::Parse(format!("Failed to convert rustdoc JSON: {}", source))
+    }
+}
+
+fn unsupported_format_message(found: u64, expected: u64) -> String {
+    let mut error_msg = format!(
+        "Incompatible rustdoc JSON format version: found {}, expected {}.",
+        found, expected
+    );
+
+    error_msg.push_str("\n\nThis means the JSON was generated with a different version of rustdoc than what doccer supports.");
+    error_msg.push_str(&format!(
+        "\nDoccer currently supports rustdoc-types v0.53.0 (JSON format version {}).",
+        expected
+    ));
+
+    if found > expected {
+        error_msg.push_str(&format!("\nThe JSON (format version {}) was generated with a newer version of rustdoc.", found));
+        error_msg.push_str("\n\nSolutions:");
+        error_msg.push_str("\n1. Update doccer to a newer version that supports format version ");
+        error_msg.push_str(&format!("{}", found));
+        error_msg.push_str("\n2. Use an older version of rustdoc that generates format version ");
+        error_msg.push_str(&format!("{}", expected));
+        error_msg.push_str("\n3. Check if there's a way to specify rustdoc format version in your toolchain");
+    } else {
+        error_msg.push_str(&format!("\nThe JSON (format version {}) was generated with an older version of rustdoc.", found));
+        error_msg.push_str("\n\nSolutions:");
+        error_msg.push_str("\n1. Update your rustdoc to generate format version ");
+        error_msg.push_str(&format!("{}", expected));
+        error_msg.push_str("\n2. Use an older version of doccer that supports format version ");
+        error_msg.push_str(&format!("{}", found));
+    }
+
+    error_msg
+}
+
+fn stdlib_not_found_message(path: &str, crate_name: Option<&str>) -> String {
+    match crate_name {
+        Some(crate_name) => format!(
+            "Standard library documentation not found at {}.\n\n\
+             To view stdlib docs, install: rustup component add rust-docs-json --toolchain nightly\n\
+             Then try: doccer {}",
+            path, crate_name
+        ),
+        None => format!("Standard library JSON file not found at {}", path),
+    }
+}
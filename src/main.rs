@@ -1,5 +1,5 @@
-use anyhow::{Context, Result};
-use clap::Parser;
+use anyhow::Result;
+use clap::{CommandFactory, FromArgMatches, Parser};
 use rustdoc_types::{Crate, Id, ItemEnum};
 use std::env;
 use std::fs;
@@ -11,6 +11,11 @@ use tracing::{debug, info};
 #[cfg(test)]
 mod tests;
 
+mod config;
+mod error;
+use config::Config;
+use error::DoccerError;
+
 // Now using official rustdoc-types structs
 
 mod parser;
@@ -21,12 +26,19 @@ use renderer::*;
 /// Types of input that can be provided to doccer
 enum InputType {
     /// External crate from docs.rs
-    ExternalCrate(String),
+    ExternalCrate {
+        name: String,              // "serde"
+        item_path: Option<String>, // "Deserializer", "de::Visitor"
+    },
     /// Local JSON file
     /// TODO: Remove this local file support fully, it is deprecated.
     LocalFile(PathBuf),
+    /// Directory of rustdoc JSON files, one crate per file
+    LocalDir(PathBuf),
     /// Local crate to generate docs for
-    LocalCrate,
+    LocalCrate {
+        item_path: Option<String>, // path below the crate root, e.g. "config::Options"
+    },
     /// Standard library documentation
     Stdlib {
         crate_name: String,          // "std", "core", "alloc"
@@ -35,7 +47,11 @@ enum InputType {
 }
 
 /// Parse JSON with enhanced error context
-fn parse_json_with_context(json_content: &str, debug: bool) -> Result<Crate> {
+fn parse_json_with_context(
+    json_content: &str,
+    debug: bool,
+    human_bytes: bool,
+) -> Result<Crate, DoccerError> {
     // First, try to detect version incompatibility before parsing
     if let Some(version_error) = check_format_version_compatibility(json_content) {
         return Err(version_error);
@@ -47,7 +63,10 @@ fn parse_json_with_context(json_content: &str, debug: bool) -> Result<Crate> {
             let mut error_msg = format!("Failed to parse JSON documentation: {}", e);
 
             // Add context about JSON size and potential issues
-            error_msg.push_str(&format!("\n\nJSON size: {} bytes", json_content.len()));
+            error_msg.push_str(&format!(
+                "\n\nJSON size: {}",
+                format_bytes(json_content.len(), human_bytes)
+            ));
 
             // If there's a position in the error, provide context
             let line = e.line();
@@ -117,7 +136,7 @@ fn parse_json_with_context(json_content: &str, debug: bool) -> Result<Crate> {
                 }
             }
 
-            Err(anyhow::anyhow!(error_msg))
+            Err(DoccerError::Parse(error_msg))
         }
     }
 }
@@ -202,55 +221,25 @@ fn extract_extended_json_snippet(json_content: &str, _line: usize, column: usize
     None
 }
 
-/// Check if the JSON has a compatible format version
-fn check_format_version_compatibility(json_content: &str) -> Option<anyhow::Error> {
-    // Try to extract the format_version field without full parsing
-    if let Ok(value) = serde_json::from_str::<serde_json::Value>(json_content) {
-        if let Some(format_version) = value.get("format_version").and_then(|v| v.as_u64()) {
-            // Version compatibility - see README.md for complete version support table
-            const SUPPORTED_VERSION: u64 = 53; // Must match rustdoc_types::FORMAT_VERSION (rustdoc-types v0.53.0)
-
-            if format_version != SUPPORTED_VERSION {
-                let mut error_msg = format!(
-                    "Incompatible rustdoc JSON format version: found {}, expected {}.",
-                    format_version, SUPPORTED_VERSION
-                );
-
-                error_msg.push_str("\n\nThis means the JSON was generated with a different version of rustdoc than what doccer supports.");
-                error_msg.push_str(&format!(
-                    "\nDoccer currently supports rustdoc-types v0.53.0 (JSON format version {}).",
-                    SUPPORTED_VERSION
-                ));
+/// Just the top-level `format_version`; every other field is skipped unparsed
+#[derive(serde::Deserialize)]
+struct FormatVersionProbe {
+    format_version: Option<u64>,
+}
 
-                if format_version > SUPPORTED_VERSION {
-                    error_msg.push_str(&format!("\nThe JSON (format version {}) was generated with a newer version of rustdoc.", format_version));
-                    error_msg.push_str("\n\nSolutions:");
-                    error_msg.push_str(
-                        "\n1. Update doccer to a newer version that supports format version ",
-                    );
-                    error_msg.push_str(&format!("{}", format_version));
-                    error_msg.push_str(
-                        "\n2. Use an older version of rustdoc that generates format version ",
-                    );
-                    error_msg.push_str(&format!("{}", SUPPORTED_VERSION));
-                    error_msg.push_str("\n3. Check if there's a way to specify rustdoc format version in your toolchain");
-                } else {
-                    error_msg.push_str(&format!("\nThe JSON (format version {}) was generated with an older version of rustdoc.", format_version));
-                    error_msg.push_str("\n\nSolutions:");
-                    error_msg.push_str("\n1. Update your rustdoc to generate format version ");
-                    error_msg.push_str(&format!("{}", SUPPORTED_VERSION));
-                    error_msg.push_str(
-                        "\n2. Use an older version of doccer that supports format version ",
-                    );
-                    error_msg.push_str(&format!("{}", format_version));
-                }
+/// Check if the JSON has a compatible format version
+fn check_format_version_compatibility(json_content: &str) -> Option<DoccerError> {
+    // Read only the format_version field, so a newer layout can't fail the check itself
+    let probe = serde_json::from_str::<FormatVersionProbe>(json_content).ok()?;
+    let format_version = probe.format_version?;
 
-                return Some(anyhow::anyhow!(error_msg));
-            }
-        }
-    }
+    // Version compatibility - see README.md for complete version support table
+    const SUPPORTED_VERSION: u64 = rustdoc_types::FORMAT_VERSION as u64;
 
-    None
+    (format_version != SUPPORTED_VERSION).then_some(DoccerError::UnsupportedFormat {
+        found: format_version,
+        expected: SUPPORTED_VERSION,
+    })
 }
 
 /// Parse the module path from an input string like "std::net" or "core::mem"
@@ -280,10 +269,16 @@ fn resolve_input(input: &str) -> InputType {
             crate_name: "alloc".to_string(),
             module_path: parse_module_path(input),
         }
+    } else if Path::new(input).is_dir() {
+        InputType::LocalDir(PathBuf::from(input))
     } else if input.ends_with(".json") || Path::new(input).exists() {
         InputType::LocalFile(PathBuf::from(input))
     } else {
-        InputType::ExternalCrate(input.to_string())
+        let name = input.split("::").next().unwrap_or(input).to_string();
+        InputType::ExternalCrate {
+            name,
+            item_path: parse_module_path(input),
+        }
     }
 }
 
@@ -296,14 +291,16 @@ fn resolve_input(input: &str) -> InputType {
     disable_version_flag = true
 )]
 struct Cli {
-    /// Input: crate name (serde), stdlib module (std::net), JSON file, or leave empty for local crate
+    /// Input: crate name (serde), item path (serde::Deserializer), stdlib module (std::net),
+    /// JSON file, directory of JSON files, or leave empty for local crate (with --crate-path, an item path within it)
     input: Option<String>,
 
     /// Crate version (defaults to "latest", can also be a specific version like "1.0.0" or "~1" for semver matching)
     #[arg(short = 'V', long = "crate-version", default_value = "latest")]
     crate_version: String,
 
-    /// Target platform (defaults to x86_64-unknown-linux-gnu)
+    /// Target platform (defaults to x86_64-unknown-linux-gnu); `all` merges the docs.rs
+    /// default targets, marking items that only some of them have
     #[arg(short, long, default_value = "x86_64-unknown-linux-gnu")]
     target: String,
 
@@ -331,22 +328,330 @@ struct Cli {
     #[arg(long)]
     no_default_features: bool,
 
+    /// Document private and crate-visible items too (local crates only)
+    #[arg(long, requires = "crate_path")]
+    include_private: bool,
+
+    /// List license, repository and authors from Cargo.toml under the crate header
+    /// (local crates only)
+    #[arg(long, requires = "crate_path")]
+    metadata: bool,
+
+    /// Leave out the `# Crate:` title and version lines, e.g. to embed the output
+    /// in a larger document (text format only)
+    #[arg(long)]
+    no_crate_header: bool,
+
     /// Toolchain to use for stdlib docs (default: nightly)
     #[arg(long, help = "Toolchain to use for stdlib docs (default: nightly)")]
     toolchain: Option<String>,
 
+    /// Read stdlib docs from this JSON file instead of the rustup toolchain directory
+    #[arg(long)]
+    stdlib_json_path: Option<PathBuf>,
+
     /// Enable debug mode with verbose JSON parsing error information
     #[arg(long)]
     debug: bool,
+
+    /// Print the parsed item tree to stderr before rendering, for diagnosing parser issues
+    #[arg(long, hide = true)]
+    dump_parsed: bool,
+
+    /// Report sizes in IEC units (e.g. `1.5 MiB`)
+    #[arg(long, overrides_with = "bytes")]
+    human: bool,
+
+    /// Report sizes as exact byte counts (the default)
+    #[arg(long, overrides_with = "human")]
+    bytes: bool,
+
+    /// Collapse private `_`-prefixed marker fields into a single note
+    #[arg(long)]
+    compact: bool,
+
+    /// Summarise the auto traits (Send, Sync, Unpin, ...) implemented by each type
+    #[arg(long)]
+    auto_traits: bool,
+
+    /// Group associated functions (no `self` receiver) separately from methods
+    #[arg(long)]
+    group_assoc: bool,
+
+    /// Suppress all generated commentary (impl summaries, sealed/auto-trait notes, group labels)
+    #[arg(long)]
+    no_auto_comment: bool,
+
+    /// Show performance and call-site attributes (`#[inline]`, `#[cold]`, `#[track_caller]`, ...)
+    /// above functions
+    #[arg(long)]
+    show_perf_attrs: bool,
+
+    /// Only show these doc-comment sections (comma separated, e.g. "Safety,Panics")
+    #[arg(long, value_delimiter = ',')]
+    sections: Option<Vec<String>>,
+
+    /// Group each module's items by kind (structs, enums, traits, ...) under headers
+    #[arg(long)]
+    group_by_kind: bool,
+
+    /// Wrap long signatures to the terminal width (80 columns when not a terminal)
+    #[arg(long)]
+    wrap: bool,
+
+    /// Wrap long signatures at this many columns
+    #[arg(long, value_name = "COLS")]
+    width: Option<usize>,
+
+    /// Inline local items at their `pub use` re-exports, noting where they are defined
+    #[arg(long)]
+    inline_reexports: bool,
+
+    /// Show types from other crates by their full path, e.g. `alloc::string::String`
+    #[arg(long)]
+    qualify_external: bool,
+
+    /// Render `#[cfg(test)]` modules and functions, present when the JSON was built
+    /// with `--cfg test`
+    #[arg(long)]
+    include_tests: bool,
+
+    /// Print signatures without any doc comments (deprecation notices are kept)
+    #[arg(long)]
+    emit_signatures_only: bool,
+
+    /// Render inherent impl blocks with their `impl<T> Type<T> where ...` headers
+    #[arg(long)]
+    show_impl_headers: bool,
+
+    /// Mark items deprecated after this version with `CHANGED since <version> (deprecated in <newer>)`
+    #[arg(long, value_name = "VERSION")]
+    since: Option<String>,
+
+    /// Render each module's own items before its submodules instead of in source order
+    #[arg(long)]
+    breadth_first: bool,
+
+    /// Order each type's trait impls alphabetically by trait name
+    #[arg(long)]
+    sort_impls: bool,
+
+    /// Render at most this many top-level items
+    #[arg(long, value_name = "N")]
+    max_items: Option<usize>,
+
+    /// Show trait default methods that impls inherit without overriding
+    #[arg(long)]
+    show_inherited: bool,
+
+    /// Hide items whose names match these glob patterns (comma separated, e.g. "__*,*_helper")
+    #[arg(long, value_delimiter = ',')]
+    exclude: Vec<String>,
+
+    /// Print item counts instead of rendering the crate
+    #[arg(long)]
+    stats: bool,
+
+    /// Only check that the input parses; prints nothing on stdout and exits nonzero on failure
+    #[arg(long, conflicts_with = "stats")]
+    check: bool,
+
+    /// Exit nonzero if any type has no supported rendering (shown as `...`), listing each one
+    #[arg(long)]
+    fail_on_unknown: bool,
+
+    /// Output format
+    #[arg(long, value_enum, default_value = "text")]
+    format: OutputFormat,
+
+    /// Write an index.txt plus one file per top-level module into this directory
+    /// instead of printing (text format only)
+    #[arg(long, value_name = "DIR")]
+    output_dir: Option<PathBuf>,
+
+    /// Read default options from this file instead of ./doccer.toml
+    #[arg(long, value_name = "PATH")]
+    config: Option<PathBuf>,
 }
 
 /// Function to handle loading a documentation JSON from a file
-fn load_from_file(file_path: &PathBuf) -> Result<String> {
+fn load_from_file(file_path: &PathBuf) -> Result<String, DoccerError> {
     info!("Loading file: {}", file_path.to_string_lossy());
 
     // Read the JSON file
-    fs::read_to_string(file_path)
-        .with_context(|| format!("Failed to read file: {}", file_path.display()))
+    fs::read_to_string(file_path).map_err(|e| DoccerError::read_file(file_path, e))
+}
+
+/// Write the `--output-dir` pages into `dir`, creating it if needed
+fn write_pages(dir: &Path, pages: &[(String, String)]) -> Result<(), DoccerError> {
+    fs::create_dir_all(dir)
+        .map_err(|e| DoccerError::io(format!("Failed to create directory: {}", dir.display()), e))?;
+    for (file_name, contents) in pages {
+        let path = dir.join(file_name);
+        fs::write(&path, contents)
+            .map_err(|e| DoccerError::io(format!("Failed to write file: {}", path.display()), e))?;
+    }
+    Ok(())
+}
+
+/// Load every `*.json` file directly inside `dir`, sorted by file name
+fn load_from_dir(dir: &Path) -> Result<Vec<String>, DoccerError> {
+    info!("Loading directory: {}", dir.display());
+
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+        .map_err(|e| DoccerError::read_file(dir, e))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort_by(|a, b| a.file_name().cmp(&b.file_name()));
+
+    paths.iter().map(load_from_file).collect()
+}
+
+/// docs.rs only builds JSON documentation for releases published on or after this date
+const DOCS_RS_JSON_CUTOFF: &str = "2025-05-23";
+
+/// A JSON-capable release that a missing `latest` can fall back to
+#[derive(Debug, PartialEq, Eq)]
+enum FallbackVersion {
+    /// A stable release, used in place of `latest` with a notice
+    Stable(String),
+    /// Only a pre-release has JSON docs, so the user has to ask for it by version
+    PreRelease(String),
+}
+
+/// Ask crates.io for the crate's versions and pick a JSON-capable fallback for `latest`
+fn find_json_capable_version(client: &reqwest::blocking::Client, name: &str) -> Option<FallbackVersion> {
+    let url = format!("https://crates.io/api/v1/crates/{}/versions", name);
+    debug!("Fetching version list from: {}", url);
+    let versions: serde_json::Value = client
+        .get(&url)
+        .header("User-Agent", concat!("doccer/", env!("CARGO_PKG_VERSION")))
+        .send()
+        .ok()?
+        .json()
+        .ok()?;
+    select_json_capable_version(&versions)
+}
+
+/// Pick the newest non-yanked version published after the JSON cutoff, other than
+/// the latest stable release (which `latest` already resolved to). Stable releases
+/// win over newer pre-releases
+fn select_json_capable_version(versions: &serde_json::Value) -> Option<FallbackVersion> {
+    let mut releases: Vec<(&str, &str)> = versions
+        .get("versions")?
+        .as_array()?
+        .iter()
+        .filter(|v| !v.get("yanked").and_then(|y| y.as_bool()).unwrap_or(false))
+        .filter_map(|v| Some((v.get("num")?.as_str()?, v.get("created_at")?.as_str()?)))
+        .collect();
+    releases.sort_by(|a, b| b.1.cmp(a.1));
+
+    let latest_stable = releases
+        .iter()
+        .find(|(num, _)| !num.contains('-'))
+        .map(|(num, _)| *num);
+
+    let candidates: Vec<&str> = releases
+        .iter()
+        .filter(|(num, _)| Some(*num) != latest_stable)
+        .filter(|(_, created_at)| *created_at >= DOCS_RS_JSON_CUTOFF)
+        .map(|(num, _)| *num)
+        .collect();
+
+    match candidates.iter().find(|num| !num.contains('-')) {
+        Some(stable) => Some(FallbackVersion::Stable(stable.to_string())),
+        None => candidates.first().map(|num| FallbackVersion::PreRelease(num.to_string())),
+    }
+}
+
+/// Ask docs.rs which targets it built for this crate version, if the version exists at all
+fn find_available_targets(
+    client: &reqwest::blocking::Client,
+    name: &str,
+    version: &str,
+) -> Option<Vec<String>> {
+    let url = format!(
+        "https://docs.rs/crate/{}/{}/menus/platforms/",
+        name,
+        version.replace("~", "%7E")
+    );
+    debug!("Probing available targets at: {}", url);
+    let response = client
+        .get(&url)
+        .header("User-Agent", concat!("doccer/", env!("CARGO_PKG_VERSION")))
+        .send()
+        .ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+    let targets = parse_platform_targets(&response.text().ok()?);
+    (!targets.is_empty()).then_some(targets)
+}
+
+/// Extract the target triples from the docs.rs platforms menu, which links each built
+/// target as `.../target-redirect/<triple>/...`
+fn parse_platform_targets(html: &str) -> Vec<String> {
+    let mut targets: Vec<String> = Vec::new();
+    for rest in html.split("target-redirect/").skip(1) {
+        let triple = rest.split(['/', '"']).next().unwrap_or_default();
+        if !triple.is_empty() && !targets.iter().any(|t| t == triple) {
+            targets.push(triple.to_string());
+        }
+    }
+    targets
+}
+
+/// Turn a 404 into `TargetNotBuilt` when the version exists but lacks `target`
+fn classify_missing_target(
+    name: &str,
+    version: &str,
+    target: &str,
+    available: Option<Vec<String>>,
+) -> DoccerError {
+    match available {
+        Some(available) if !available.iter().any(|t| t == target) => DoccerError::TargetNotBuilt {
+            name: name.to_string(),
+            version: version.to_string(),
+            target: target.to_string(),
+            available,
+        },
+        _ => DoccerError::NotFound {
+            name: name.to_string(),
+            version: version.to_string(),
+            target: target.to_string(),
+        },
+    }
+}
+
+/// Whether a missing `latest` should be retried with an older JSON-capable release.
+/// Only a version without JSON qualifies; a target that was never built would be
+/// missing from the older release too
+fn falls_back_to_older_release(version: &str, missing: &DoccerError) -> bool {
+    version == "latest" && matches!(missing, DoccerError::NotFound { .. })
+}
+
+/// Map a docs.rs response status to `NotFound` or a network error
+fn check_docs_rs_status(
+    status: reqwest::StatusCode,
+    name: &str,
+    version: &str,
+    target: &str,
+) -> Result<(), DoccerError> {
+    if status == reqwest::StatusCode::NOT_FOUND {
+        Err(DoccerError::NotFound {
+            name: name.to_string(),
+            version: version.to_string(),
+            target: target.to_string(),
+        })
+    } else if !status.is_success() {
+        Err(DoccerError::Network {
+            message: format!("Failed to fetch documentation: HTTP {}", status),
+            source: None,
+        })
+    } else {
+        Ok(())
+    }
 }
 
 /// Function to fetch documentation JSON from docs.rs
@@ -355,7 +660,8 @@ fn fetch_from_docs_rs(
     version: &str,
     target: &str,
     format_version: Option<&str>,
-) -> Result<String> {
+    human_bytes: bool,
+) -> Result<String, DoccerError> {
     // Build the URL based on the parameters
     let mut url = if target == "x86_64-unknown-linux-gnu" {
         // Default target can be omitted
@@ -395,26 +701,36 @@ fn fetch_from_docs_rs(
         .header("User-Agent", concat!("doccer/", env!("CARGO_PKG_VERSION")))
         .header("Accept", "application/json, application/zstd")
         .send()
-        .with_context(|| format!("Failed to fetch documentation from {}", url))?;
-
-    if response.status().as_u16() == 404 {
-        return Err(anyhow::anyhow!(
-            "Documentation not found for crate '{}' version '{}' on target '{}'. \n\
-             This could be because:\n\
-             1. The crate doesn't exist\n\
-             2. The version doesn't exist\n\
-             3. The target isn't supported\n\
-             4. The crate version was published before May 23, 2025\n\n\
-             Note: docs.rs only generates JSON documentation for crates published after May 23, 2025.\n\
-             Try a newer version or try a different crate like 'clap' (4.3.0+) which has JSON documentation.",
-            name, version, target
-        ));
-    } else if !response.status().is_success() {
-        return Err(anyhow::anyhow!(
-            "Failed to fetch documentation: HTTP {}",
-            response.status()
-        ));
+        .map_err(|e| {
+            DoccerError::network(format!("Failed to fetch documentation from {}", url), e)
+        })?;
+
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        let available = find_available_targets(&client, name, version);
+        let missing = classify_missing_target(name, version, target, available);
+        // The newest release may predate docs.rs JSON support; fall back to the
+        // newest release that has JSON docs
+        if falls_back_to_older_release(version, &missing) {
+            match find_json_capable_version(&client, name) {
+                Some(FallbackVersion::Stable(fallback)) => {
+                    eprintln!(
+                        "No JSON documentation for the latest release of '{}'; using version {} instead",
+                        name, fallback
+                    );
+                    return fetch_from_docs_rs(name, &fallback, target, format_version, human_bytes);
+                }
+                Some(FallbackVersion::PreRelease(version)) => {
+                    return Err(DoccerError::PreReleaseOnly {
+                        name: name.to_string(),
+                        version,
+                    });
+                }
+                None => {}
+            }
+        }
+        return Err(missing);
     }
+    check_docs_rs_status(response.status(), name, version, target)?;
 
     // Print the final URL after redirects
     let final_url = response.url().clone();
@@ -440,43 +756,29 @@ fn fetch_from_docs_rs(
             .get(&new_url)
             .header("User-Agent", concat!("doccer/", env!("CARGO_PKG_VERSION")))
             .send()
-            .with_context(|| format!("Failed to fetch documentation from {new_url}"))?;
+            .map_err(|e| {
+                DoccerError::network(format!("Failed to fetch documentation from {new_url}"), e)
+            })?;
 
-        if response.status().as_u16() == 404 {
-            return Err(anyhow::anyhow!(
-                "Documentation not found for crate '{}' version '{}' on target '{}'. \n\
-                 This could be because:\n\
-                 1. The crate doesn't exist\n\
-                 2. The version doesn't exist\n\
-                 3. The target isn't supported\n\
-                 4. The crate version was published before May 23, 2025\n\n\
-                 Note: docs.rs only generates JSON documentation for crates published after May 23, 2025.\n\
-                 Try a newer version or try a different crate like 'clap' (4.3.0+) which has JSON documentation.",
-                name, version, target
-            ));
-        } else if !response.status().is_success() {
-            return Err(anyhow::anyhow!(
-                "Failed to fetch documentation: HTTP {}",
-                response.status()
-            ));
-        }
+        check_docs_rs_status(response.status(), name, version, target)?;
 
         // Read response as bytes
         let bytes = response.bytes()?;
-        debug!("Downloaded {} bytes", bytes.len());
+        debug!("Downloaded {}", format_bytes(bytes.len(), human_bytes));
 
         // For .json.zst URLs, always use zstd decompression
         debug!("Decompressing zstd data...");
-        let decompressed =
-            zstd::decode_all(io::Cursor::new(bytes)).context("Failed to decompress zstd data")?;
+        let decompressed = zstd::decode_all(io::Cursor::new(bytes))
+            .map_err(|e| DoccerError::io("Failed to decompress zstd data", e))?;
 
-        return String::from_utf8(decompressed)
-            .context("Failed to convert decompressed data to UTF-8");
+        return String::from_utf8(decompressed).map_err(|e| {
+            DoccerError::Parse(format!("Failed to convert decompressed data to UTF-8: {}", e))
+        });
     }
 
     // Read response as bytes for the original URL
     let bytes = response.bytes()?;
-    debug!("Downloaded {} bytes", bytes.len());
+    debug!("Downloaded {}", format_bytes(bytes.len(), human_bytes));
 
     let json_content = if content_type.contains("application/zstd")
         || final_url.path().ends_with(".zst")
@@ -485,29 +787,68 @@ fn fetch_from_docs_rs(
         // zstd magic number
         debug!("Decompressing zstd data...");
         // Decompress with zstd
-        let decompressed =
-            zstd::decode_all(io::Cursor::new(bytes)).context("Failed to decompress zstd data")?;
+        let decompressed = zstd::decode_all(io::Cursor::new(bytes))
+            .map_err(|e| DoccerError::io("Failed to decompress zstd data", e))?;
 
-        String::from_utf8(decompressed).context("Failed to convert decompressed data to UTF-8")?
+        String::from_utf8(decompressed).map_err(|e| {
+            DoccerError::Parse(format!("Failed to convert decompressed data to UTF-8: {}", e))
+        })?
     } else {
         // Just read the regular JSON content
         debug!("Using raw JSON content");
-        String::from_utf8(bytes.to_vec()).context("Failed to convert response data to UTF-8")?
+        String::from_utf8(bytes.to_vec()).map_err(|e| {
+            DoccerError::Parse(format!("Failed to convert response data to UTF-8: {}", e))
+        })?
     };
 
     Ok(json_content)
 }
 
-/// Function to filter a Crate structure to show only items in a specific module path
-fn filter_by_module_path(crate_data: &mut Crate, module_path: &str) -> Result<()> {
-    // Split module path into segments
-    let segments: Vec<&str> = module_path.split("::").collect();
+/// The targets docs.rs builds unless a crate asks for others, fetched by `--target all`
+const DOCS_RS_DEFAULT_TARGETS: &[&str] = &[
+    "x86_64-unknown-linux-gnu",
+    "x86_64-apple-darwin",
+    "x86_64-pc-windows-msvc",
+    "i686-unknown-linux-gnu",
+    "i686-pc-windows-msvc",
+];
+
+/// Fetch the JSON for each docs.rs default target, skipping targets the crate wasn't built for
+fn fetch_all_targets(
+    name: &str,
+    version: &str,
+    format_version: Option<&str>,
+    human_bytes: bool,
+) -> Result<Vec<(String, String)>, DoccerError> {
+    let mut builds = Vec::new();
+    for target in DOCS_RS_DEFAULT_TARGETS {
+        match fetch_from_docs_rs(name, version, target, format_version, human_bytes) {
+            Ok(json) => builds.push((target.to_string(), json)),
+            Err(DoccerError::TargetNotBuilt { .. }) => info!("Skipping {}: not built on docs.rs", target),
+            Err(e) => return Err(e),
+        }
+    }
+    if builds.is_empty() {
+        return Err(DoccerError::NotFound {
+            name: name.to_string(),
+            version: version.to_string(),
+            target: "all".to_string(),
+        });
+    }
+    Ok(builds)
+}
+
+/// Function to filter a Crate structure to a module path, or to a single item and its impls
+pub(crate) fn filter_by_path(crate_data: &mut Crate, path: &str) -> Result<(), DoccerError> {
+    // Split the path into segments
+    let segments: Vec<&str> = path.split("::").collect();
 
     // Start from the root module
     let mut current_module_id = crate_data.root;
     let mut current_module_name = "root".to_string();
+    let mut parent_module_id = crate_data.root;
 
-    // Traverse the module hierarchy to find the target module
+    // Traverse the module hierarchy to find the target module or item
     for segment in &segments {
         let mut found = false;
 
@@ -520,7 +861,8 @@ fn filter_by_module_path(crate_data: &mut Crate, module_path: &str) -> Result<()
                     if let Some(item) = crate_data.index.get(item_id) {
                         if let Some(name) = &item.name {
                             if name == segment {
-                                // Found the next module in the path
+                                // Found the next item in the path
+                                parent_module_id = current_module_id;
                                 current_module_id = *item_id;
                                 current_module_name = name.clone();
                                 found = true;
@@ -533,14 +875,31 @@ fn filter_by_module_path(crate_data: &mut Crate, module_path: &str) -> Result<()
         }
 
         if !found {
-            return Err(anyhow::anyhow!(
-                "Module '{}' not found in the path '{}'",
-                segment,
-                module_path
-            ));
+            return Err(DoccerError::ModuleNotFound {
+                segment: segment.to_string(),
+                path: path.to_string(),
+            });
         }
     }
 
+    let target_is_module = matches!(
+        crate_data.index.get(&current_module_id).map(|item| &item.inner),
+        Some(ItemEnum::Module(_))
+    );
+    if !target_is_module {
+        // Render the enclosing module with only the requested item in it. The index
+        // is left intact so the item's impls and their methods still resolve.
+        crate_data.root = parent_module_id;
+        if let Some(parent) = crate_data.index.get_mut(&parent_module_id) {
+            parent.docs = None;
+            parent.attrs.clear();
+            if let ItemEnum::Module(module_data) = &mut parent.inner {
+                module_data.items = vec![current_module_id];
+            }
+        }
+        return Ok(());
+    }
+
     // At this point, current_module_id points to the target module
     // Update the crate's root to point to the target module
     crate_data.root = current_module_id;
@@ -587,7 +946,36 @@ fn filter_by_module_path(crate_data: &mut Crate, module_path: &str) -> Result<()
 }
 
 /// Function to load standard library documentation from local rustup installation
-fn load_stdlib_docs(crate_name: &str, toolchain: Option<&str>) -> Result<String> {
+fn load_stdlib_docs(
+    crate_name: &str,
+    toolchain: Option<&str>,
+    json_path_override: Option<&Path>,
+) -> Result<String, DoccerError> {
+    let json_path = resolve_stdlib_json_path(crate_name, toolchain, json_path_override)?;
+
+    if json_path.exists() {
+        info!("Loading stdlib JSON from: {}", json_path.display());
+        fs::read_to_string(&json_path).map_err(|e| DoccerError::io("Failed to read stdlib JSON", e))
+    } else {
+        Err(DoccerError::StdlibNotFound {
+            path: json_path.display().to_string(),
+            crate_name: json_path_override.is_none().then(|| crate_name.to_string()),
+        })
+    }
+}
+
+/// Locate the stdlib JSON for a crate, preferring an explicit override over the
+/// rustup toolchain layout
+fn resolve_stdlib_json_path(
+    crate_name: &str,
+    toolchain: Option<&str>,
+    json_path_override: Option<&Path>,
+) -> Result<PathBuf, DoccerError> {
+    // An explicit file needs neither a toolchain nor the host target triple
+    if let Some(path) = json_path_override {
+        return Ok(path.to_path_buf());
+    }
+
     let toolchain = toolchain.unwrap_or("nightly");
 
     // Get target triple for current system
@@ -597,31 +985,21 @@ fn load_stdlib_docs(crate_name: &str, toolchain: Option<&str>) -> Result<String>
         Ok(home) => PathBuf::from(home),
         Err(_) => match dirs::home_dir() {
             Some(home) => home,
-            None => return Err(anyhow::anyhow!("Could not determine home directory")),
+            None => {
+                return Err(DoccerError::Environment("Could not determine home directory".to_string()))
+            }
         },
     };
 
-    let json_path = home_dir
+    Ok(home_dir
         .join(".rustup/toolchains")
         .join(format!("{toolchain}-{target_triple}"))
         .join("share/doc/rust/json")
-        .join(format!("{crate_name}.json"));
-
-    if json_path.exists() {
-        info!("Loading stdlib JSON from: {}", json_path.display());
-        fs::read_to_string(json_path).context("Failed to read stdlib JSON")
-    } else {
-        Err(anyhow::anyhow!(
-            "Standard library documentation not found at {}.\n\n\
-             To view stdlib docs, install: rustup component add rust-docs-json --toolchain nightly\n\
-             Then try: doccer {}",
-            json_path.display(), crate_name
-        ))
-    }
+        .join(format!("{crate_name}.json")))
 }
 
 /// Get the current system's target triple (e.g., x86_64-apple-darwin)
-fn get_target_triple() -> Result<String> {
+fn get_target_triple() -> Result<String, DoccerError> {
     // Try to get from rustc
     let output = std::process::Command::new("rustc")
         .args(["--version", "--verbose"])
@@ -635,8 +1013,8 @@ fn get_target_triple() -> Result<String> {
                     return Ok(stripped.to_string());
                 }
             }
-            Err(anyhow::anyhow!(
-                "Could not determine target triple from rustc output"
+            Err(DoccerError::Environment(
+                "Could not determine target triple from rustc output".to_string(),
             ))
         }
         Err(_) => {
@@ -673,33 +1051,17 @@ fn get_target_triple() -> Result<String> {
                     any(target_arch = "x86_64", target_arch = "aarch64")
                 )
             )))]
-            Err(anyhow::anyhow!(
-                "Could not determine target triple for current system"
+            Err(DoccerError::Environment(
+                "Could not determine target triple for current system".to_string(),
             ))
         }
     }
 }
 
 /// Function to generate documentation JSON for a local crate using rustdoc-json crate
-fn generate_local_crate_docs(
-    crate_path: &Path,
-    package: Option<&String>,
-    features: Option<&String>,
-    all_features: bool,
-    no_default_features: bool,
-) -> Result<String> {
-    info!("Generating documentation for local crate...");
-
-    // Ensure the crate path exists
-    if !crate_path.exists() {
-        return Err(anyhow::anyhow!(
-            "Crate path does not exist: {}",
-            crate_path.display()
-        ));
-    }
-
-    // Find the manifest path (Cargo.toml)
-    let manifest_path = if let Some(pkg) = package {
+/// Find the `Cargo.toml` for a local crate, or for `package` within a workspace
+fn resolve_manifest_path(crate_path: &Path, package: Option<&String>) -> PathBuf {
+    if let Some(pkg) = package {
         // For workspace packages, find the specific package's Cargo.toml
         let potential_paths = [
             crate_path.join(format!("{pkg}/Cargo.toml")),
@@ -721,14 +1083,73 @@ fn generate_local_crate_docs(
     } else {
         // For single crates, use the main Cargo.toml
         crate_path.join("Cargo.toml")
-    };
+    }
+}
+
+/// The `[package]` table of a local manifest
+fn read_package_table(manifest_path: &Path) -> Option<toml::Table> {
+    let mut manifest: toml::Table = fs::read_to_string(manifest_path).ok()?.parse().ok()?;
+    match manifest.remove("package")? {
+        toml::Value::Table(package) => Some(package),
+        _ => None,
+    }
+}
+
+/// The `[package] version` a local manifest declares, for JSON without `crate_version`
+fn manifest_version(manifest_path: &Path) -> Option<String> {
+    read_package_table(manifest_path)?
+        .get("version")?
+        .as_str()
+        .map(str::to_string)
+}
+
+/// License, repository and authors from a local manifest, for `--metadata`.
+/// Values inherited from the workspace (`license.workspace = true`) are skipped.
+fn manifest_metadata(manifest_path: &Path) -> Option<CrateMetadata> {
+    let package = read_package_table(manifest_path)?;
+    let string = |key: &str| package.get(key).and_then(|v| v.as_str()).map(str::to_string);
+    Some(CrateMetadata {
+        license: string("license"),
+        repository: string("repository"),
+        authors: package
+            .get("authors")
+            .and_then(|a| a.as_array())
+            .map(|authors| {
+                authors
+                    .iter()
+                    .filter_map(|a| a.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default(),
+    })
+}
+
+fn generate_local_crate_docs(
+    crate_path: &Path,
+    package: Option<&String>,
+    features: Option<&String>,
+    all_features: bool,
+    no_default_features: bool,
+    include_private: bool,
+) -> Result<String, DoccerError> {
+    info!("Generating documentation for local crate...");
+
+    // Ensure the crate path exists
+    if !crate_path.exists() {
+        return Err(DoccerError::LocalCrate(format!(
+            "Crate path does not exist: {}",
+            crate_path.display()
+        )));
+    }
+
+    let manifest_path = resolve_manifest_path(crate_path, package);
 
     // Verify the manifest path exists
     if !manifest_path.exists() {
-        return Err(anyhow::anyhow!(
+        return Err(DoccerError::LocalCrate(format!(
             "Cargo.toml not found at: {}",
             manifest_path.display()
-        ));
+        )));
     }
 
     info!("Using manifest path: {}", manifest_path.display());
@@ -736,7 +1157,7 @@ fn generate_local_crate_docs(
     // Configure the rustdoc-json builder
     let mut builder = rustdoc_json::Builder::default()
         .toolchain("nightly")
-        .manifest_path(manifest_path);
+        .manifest_path(&manifest_path);
 
     // Apply package filter if specified
     if let Some(pkg) = package {
@@ -752,6 +1173,9 @@ fn generate_local_crate_docs(
             .filter(|s| !s.is_empty())
             .collect();
 
+        // Catch typos here rather than as an opaque cargo failure from the builder
+        validate_features(&manifest_path, &feature_vec)?;
+
         builder = builder.features(feature_vec);
     }
 
@@ -763,10 +1187,15 @@ fn generate_local_crate_docs(
         builder = builder.no_default_features(true);
     }
 
-    // Build the documentation
+    if include_private {
+        builder = builder.document_private_items(true);
+    }
+
+    // Build the documentation; anything cargo prints goes to stderr so stdout
+    // carries only the rendered output
     let json_path = builder
-        .build()
-        .map_err(|e| anyhow::anyhow!("Failed to generate rustdoc JSON: {}", e))?;
+        .build_with_captured_output(std::io::stderr(), std::io::stderr())
+        .map_err(|e| DoccerError::LocalCrate(format!("Failed to generate rustdoc JSON: {}", e)))?;
 
     info!(
         "Successfully generated documentation at: {}",
@@ -774,25 +1203,124 @@ fn generate_local_crate_docs(
     );
 
     // Read the generated JSON file
-    fs::read_to_string(&json_path).with_context(|| {
-        format!(
-            "Failed to read generated JSON file: {}",
-            json_path.display()
+    fs::read_to_string(&json_path).map_err(|e| {
+        DoccerError::io(
+            format!("Failed to read generated JSON file: {}", json_path.display()),
+            e,
         )
     })
 }
 
+/// Check requested features against those the manifest declares.
+///
+/// Declared features are the keys of `[features]` plus the implicit features of
+/// optional dependencies. `default` is always accepted and `dep/feature` entries
+/// are left for cargo to resolve. `--all-features` and `--no-default-features`
+/// don't change which names exist, so requested features are checked either way.
+fn validate_features(manifest_path: &Path, requested: &[String]) -> Result<(), DoccerError> {
+    let manifest = fs::read_to_string(manifest_path).map_err(|e| {
+        DoccerError::io(format!("Failed to read manifest: {}", manifest_path.display()), e)
+    })?;
+    let manifest: toml::Table = manifest.parse().map_err(|e| {
+        DoccerError::Parse(format!("Failed to parse manifest {}: {}", manifest_path.display(), e))
+    })?;
+
+    // A virtual workspace manifest declares no features of its own
+    if !manifest.contains_key("package") {
+        return Ok(());
+    }
+
+    let mut available: Vec<String> = manifest
+        .get("features")
+        .and_then(|f| f.as_table())
+        .map(|f| f.keys().cloned().collect())
+        .unwrap_or_default();
+
+    if let Some(deps) = manifest.get("dependencies").and_then(|d| d.as_table()) {
+        for (name, spec) in deps {
+            let optional = spec
+                .get("optional")
+                .and_then(|o| o.as_bool())
+                .unwrap_or(false);
+            if optional && !available.contains(name) {
+                available.push(name.clone());
+            }
+        }
+    }
+    available.sort();
+
+    let unknown: Vec<String> = requested
+        .iter()
+        .filter(|f| *f != "default" && !f.contains('/') && !available.contains(f))
+        .cloned()
+        .collect();
+
+    if unknown.is_empty() {
+        return Ok(());
+    }
+
+    Err(DoccerError::UnknownFeatures {
+        manifest: manifest_path.display().to_string(),
+        unknown,
+        available,
+    })
+}
+
+/// Format a byte count the same way regardless of locale: an exact count such
+/// as `1048576 bytes`, or with `--human` IEC units such as `1.0 MiB`
+fn format_bytes(bytes: usize, human: bool) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if !human || bytes < 1024 {
+        return format!("{} bytes", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+/// Width used by `--wrap` when stdout is not a terminal
+const FALLBACK_WRAP_WIDTH: usize = 80;
+
+/// Pick the signature wrap width: an explicit `--width` wins, `--wrap` follows the
+/// terminal, and otherwise the renderer's default applies
+fn resolve_wrap_width(explicit: Option<usize>, wrap: bool, terminal: Option<usize>) -> usize {
+    match explicit {
+        Some(width) => width,
+        None if wrap => terminal.unwrap_or(FALLBACK_WRAP_WIDTH),
+        None => renderer::components::WHERE_CLAUSE_WRAP_WIDTH,
+    }
+}
+
+/// Column count of the terminal attached to stdout, if there is one
+fn terminal_width() -> Option<usize> {
+    let (terminal_size::Width(columns), _) = terminal_size::terminal_size_of(io::stdout())?;
+    (columns > 0).then_some(columns as usize)
+}
+
 fn main() -> Result<()> {
-    // Initialize tracing with environment filter (defaults to no output)
+    // Initialize tracing with environment filter (defaults to no output).
+    // Logs go to stderr so they never mix with the rendered docs on stdout.
     tracing_subscriber::fmt()
         .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+        .with_writer(std::io::stderr)
         .init();
 
-    let cli = Cli::parse();
+    let matches = Cli::command().get_matches();
+    let mut cli = Cli::from_arg_matches(&matches)?;
+    // Options from the config file only fill in flags missing from the command line
+    if let Some(config) = Config::load(cli.config.as_deref())? {
+        config.apply(&mut cli, &matches)?;
+    }
 
     // Determine the input type based on CLI arguments
     let input_type = if cli.crate_path.is_some() {
-        InputType::LocalCrate
+        InputType::LocalCrate {
+            item_path: cli.input.as_deref().and_then(parse_module_path),
+        }
     } else if let Some(input) = &cli.input {
         resolve_input(input)
     } else {
@@ -802,18 +1330,26 @@ fn main() -> Result<()> {
         ));
     };
 
+    if cli.target == "all" && !matches!(input_type, InputType::ExternalCrate { .. }) {
+        return Err(anyhow::anyhow!("--target all only applies to crates fetched from docs.rs"));
+    }
+
+    // With `--target all`, the target each JSON was built for, in input order
+    let mut merged_targets = Vec::new();
+
     // Process input based on type
-    let json_content = match &input_type {
-        InputType::LocalCrate => {
+    let json_contents = match &input_type {
+        InputType::LocalCrate { .. } => {
             // Local crate mode (if --crate-path is provided)
             if let Some(crate_path) = &cli.crate_path {
-                generate_local_crate_docs(
+                vec![generate_local_crate_docs(
                     crate_path,
                     cli.package.as_ref(),
                     cli.features.as_ref(),
                     cli.all_features,
                     cli.no_default_features,
-                )?
+                    cli.include_private,
+                )?]
             } else {
                 return Err(anyhow::anyhow!(
                     "Missing --crate-path argument for local crate mode"
@@ -822,49 +1358,184 @@ fn main() -> Result<()> {
         }
         InputType::LocalFile(path) => {
             // Local file mode
-            load_from_file(path)?
+            vec![load_from_file(path)?]
         }
-        InputType::ExternalCrate(name) => {
+        InputType::LocalDir(dir) => {
+            // One crate per JSON file, rendered in file name order
+            load_from_dir(dir)?
+        }
+        InputType::ExternalCrate { name, .. } if cli.target == "all" => {
+            // One build per target, merged into a single crate after parsing
+            let builds = fetch_all_targets(
+                name,
+                &cli.crate_version,
+                cli.format_version.as_deref(),
+                cli.human,
+            )?;
+            let contents;
+            (merged_targets, contents) = builds.into_iter().unzip();
+            contents
+        }
+        InputType::ExternalCrate { name, .. } => {
             // Docs.rs mode
-            fetch_from_docs_rs(
+            vec![fetch_from_docs_rs(
                 name,
                 &cli.crate_version,
                 &cli.target,
                 cli.format_version.as_deref(),
-            )?
+                cli.human,
+            )?]
         }
         InputType::Stdlib {
             crate_name,
             module_path: _,
         } => {
             // Standard library mode
-            load_stdlib_docs(crate_name, cli.toolchain.as_deref())?
+            vec![load_stdlib_docs(
+                crate_name,
+                cli.toolchain.as_deref(),
+                cli.stdlib_json_path.as_deref(),
+            )?]
         }
     };
 
-    // Parse the JSON content
-    let mut crate_data: Crate = parse_json_with_context(&json_content, cli.debug)?;
+    // Phase 2 settings are shared by every crate in the input
+    let mut context = RenderContext::new();
+    context.show_private = cli.include_private;
+    context.compact = cli.compact;
+    context.auto_traits = cli.auto_traits;
+    context.group_assoc = cli.group_assoc;
+    context.no_auto_comment = cli.no_auto_comment;
+    context.show_perf_attrs = cli.show_perf_attrs;
+    context.sections = cli.sections;
+    context.group_by_kind = cli.group_by_kind;
+    context.show_inherited = cli.show_inherited;
+    context.signatures_only = cli.emit_signatures_only;
+    context.show_impl_headers = cli.show_impl_headers;
+    context.since = cli.since;
+    context.breadth_first = cli.breadth_first;
+    context.sort_impls = cli.sort_impls;
+    context.max_items = cli.max_items;
+    context.no_crate_header = cli.no_crate_header;
+    if let (true, Some(crate_path)) = (cli.metadata, &cli.crate_path) {
+        let manifest_path = resolve_manifest_path(crate_path, cli.package.as_ref());
+        context.metadata = manifest_metadata(&manifest_path);
+    }
+    context.width = resolve_wrap_width(cli.width, cli.wrap, terminal_width());
+    context.format = cli.format;
+    if cli.output_dir.is_some() && matches!(context.format, OutputFormat::Man) {
+        return Err(anyhow::anyhow!("--output-dir only supports the text format"));
+    }
 
-    // If this is a stdlib request with a module path, filter to that module
-    if let InputType::Stdlib {
-        crate_name: _,
-        module_path: Some(ref path),
-    } = input_type
-    {
-        filter_by_module_path(&mut crate_data, path)?;
+    let mut parsed_crates = Vec::new();
+    for json_content in &json_contents {
+        // Parse the JSON content
+        let mut crate_data: Crate = parse_json_with_context(json_content, cli.debug, cli.human)?;
+
+        // If a module or item path was requested, filter down to it
+        let path_filter = match &input_type {
+            InputType::Stdlib { module_path, .. } => module_path.as_deref(),
+            InputType::ExternalCrate { item_path, .. } | InputType::LocalCrate { item_path } => {
+                item_path.as_deref()
+            }
+            InputType::LocalFile(_) | InputType::LocalDir(_) => None,
+        };
+        if let Some(path) = path_filter {
+            filter_by_path(&mut crate_data, path)?;
+        }
+
+        // rustdoc only records the version when cargo passes one along, so fall
+        // back to the manifest for local crates
+        if crate_data.crate_version.is_none() {
+            if let Some(crate_path) = &cli.crate_path {
+                let manifest_path = resolve_manifest_path(crate_path, cli.package.as_ref());
+                crate_data.crate_version = manifest_version(&manifest_path);
+            }
+        }
+
+        // Two-phase approach: Parse then Render
+
+        // Phase 1: Parse JSON into structured data
+        let parser = ItemParser::new(&crate_data)
+            .with_inline_reexports(cli.inline_reexports)
+            .with_qualified_external_paths(cli.qualify_external)
+            .with_test_items(cli.include_tests);
+        let parsed_module = parser.parse_crate()?;
+
+        if cli.dump_parsed {
+            eprintln!("{:#?}", parsed_module);
+        }
+
+        let unknown_types = parser.unknown_types();
+        if cli.fail_on_unknown && !unknown_types.is_empty() {
+            let listing: Vec<String> = unknown_types.iter().map(|t| format!("  {}", t)).collect();
+            return Err(anyhow::anyhow!(
+                "{} unsupported type(s) in crate '{}':\n{}",
+                unknown_types.len(),
+                parsed_module.name,
+                listing.join("\n")
+            ));
+        }
+
+        parsed_crates.push((parsed_module, crate_data.crate_version));
     }
 
-    // Two-phase approach: Parse then Render
+    if !merged_targets.is_empty() {
+        // Every target shares the crate version
+        let version = parsed_crates.first().and_then(|(_, version)| version.clone());
+        let builds = merged_targets
+            .into_iter()
+            .zip(parsed_crates.into_iter().map(|(module, _)| module))
+            .collect();
+        parsed_crates = merge_targets(builds).map(|module| (module, version)).into_iter().collect();
+    }
 
-    // Phase 1: Parse JSON into structured data
-    let parser = ItemParser::new(&crate_data);
-    let parsed_module = parser.parse_crate()?;
+    let crate_count = parsed_crates.len();
+    let mut outputs = Vec::new();
+    for (mut parsed_module, crate_version) in parsed_crates {
+        if cli.check {
+            eprintln!("{}: ok", parsed_module.name);
+            continue;
+        }
 
-    // Phase 2: Render structured data to text
-    let renderer = ParsedRenderer;
-    let output = renderer.render(&parsed_module, crate_data.crate_version.as_deref());
+        exclude_items(&mut parsed_module, &cli.exclude);
 
-    println!("{output}");
+        if cli.stats {
+            println!("{}", CrateStats::collect(&parsed_module));
+            continue;
+        }
+
+        if let Some(output_dir) = &cli.output_dir {
+            // A directory of inputs gets one subdirectory per crate
+            let dir = if crate_count > 1 {
+                output_dir.join(&parsed_module.name)
+            } else {
+                output_dir.clone()
+            };
+            let pages =
+                ParsedRenderer.render_split(&parsed_module, crate_version.as_deref(), &context);
+            write_pages(&dir, &pages)?;
+            continue;
+        }
+
+        // Phase 2: Render structured data to text
+        outputs.push(match context.format {
+            OutputFormat::Text => ParsedRenderer.render(
+                &parsed_module,
+                crate_version.as_deref(),
+                &context,
+            ),
+            OutputFormat::Man => ManRenderer.render(
+                &parsed_module,
+                crate_version.as_deref(),
+                &context,
+            ),
+        });
+    }
+
+    if !outputs.is_empty() {
+        println!("{}", outputs.concat());
+    }
 
     Ok(())
 }
@@ -0,0 +1,72 @@
+use crate::parser::types::*;
+
+/// Drop items whose names match any of `patterns`, along with modules left empty
+/// by the pruning. Patterns are globs where `*` matches any run of characters and
+/// `?` a single character, e.g. `__*` or `*_helper`.
+pub fn exclude_items(module: &mut ParsedModule, patterns: &[String]) {
+    if patterns.is_empty() {
+        return;
+    }
+
+    module.items.retain_mut(|item| {
+        if item_name(item).is_some_and(|name| patterns.iter().any(|p| glob_match(p, name))) {
+            return false;
+        }
+        let child = match item {
+            ParsedItem::Module(child) => child,
+            ParsedItem::Targeted(t) => match t.item.as_mut() {
+                ParsedItem::Module(child) => child,
+                _ => return true,
+            },
+            _ => return true,
+        };
+        let had_items = !child.items.is_empty();
+        exclude_items(child, patterns);
+        !had_items || !child.items.is_empty()
+    });
+}
+
+pub(crate) fn item_name(item: &ParsedItem) -> Option<&str> {
+    match item {
+        ParsedItem::Function(f) => Some(&f.signature.name),
+        ParsedItem::Struct(s) => Some(&s.name),
+        ParsedItem::Enum(e) => Some(&e.name),
+        ParsedItem::Trait(t) => Some(&t.name),
+        ParsedItem::Constant(c) => Some(&c.name),
+        ParsedItem::Static(s) => Some(&s.name),
+        ParsedItem::TypeAlias(t) => Some(&t.name),
+        ParsedItem::Module(m) => Some(&m.name),
+        ParsedItem::Macro(m) => m
+            .signature
+            .strip_prefix("macro_rules! ")
+            .and_then(|rest| rest.split(|c: char| !c.is_alphanumeric() && c != '_').next()),
+        ParsedItem::TraitImpl(_) => None,
+        ParsedItem::ReExport(r) => item_name(&r.item),
+        ParsedItem::Targeted(t) => item_name(&t.item),
+    }
+}
+
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    // Iterative wildcard match, backtracking to the most recent `*`
+    let (mut p, mut n) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, n));
+            p += 1;
+        } else if let Some((star_p, star_n)) = star {
+            p = star_p + 1;
+            n = star_n + 1;
+            star = Some((star_p, star_n + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
@@ -0,0 +1,93 @@
+use crate::parser::filter::item_name;
+use crate::parser::types::*;
+use std::collections::HashMap;
+use std::mem::{discriminant, Discriminant};
+
+/// Kind, name and occurrence index, which together identify an item across targets
+type ItemKey = (Discriminant<ParsedItem>, String, usize);
+
+/// Merge the same crate parsed once per target into a single tree. Items missing
+/// from some of the targets are wrapped in `ParsedItem::Targeted`, naming the
+/// targets that have them; modules found on several targets are merged recursively.
+/// The first target's module supplies the docs and attributes of the result.
+pub fn merge_targets(builds: Vec<(String, ParsedModule)>) -> Option<ParsedModule> {
+    if builds.is_empty() {
+        return None;
+    }
+    Some(merge_modules(builds))
+}
+
+fn merge_modules(builds: Vec<(String, ParsedModule)>) -> ParsedModule {
+    let mut items = Vec::with_capacity(builds.len());
+    let mut first = None;
+    for (target, mut module) in builds {
+        items.push((target, std::mem::take(&mut module.items)));
+        first.get_or_insert(module);
+    }
+    let mut module = first.expect("merge_modules is called with at least one module");
+    module.items = merge_items(items);
+    module
+}
+
+/// Merge one module's items across targets, in the order the first target lists
+/// them followed by items only later targets have
+fn merge_items(builds: Vec<(String, Vec<ParsedItem>)>) -> Vec<ParsedItem> {
+    let target_count = builds.len();
+    let mut order: Vec<ItemKey> = Vec::new();
+    let mut found: HashMap<ItemKey, Vec<(String, ParsedItem)>> = HashMap::new();
+
+    for (target, items) in builds {
+        let mut occurrences: HashMap<(Discriminant<ParsedItem>, String), usize> = HashMap::new();
+        for item in items {
+            let (kind, name) = item_key(&item);
+            let nth = occurrences.entry((kind, name.clone())).or_default();
+            let key = (kind, name, *nth);
+            *nth += 1;
+            found
+                .entry(key.clone())
+                .or_insert_with(|| {
+                    order.push(key);
+                    Vec::new()
+                })
+                .push((target.clone(), item));
+        }
+    }
+
+    order
+        .into_iter()
+        .filter_map(|key| found.remove(&key))
+        .map(|copies| {
+            let targets: Vec<String> = copies.iter().map(|(target, _)| target.clone()).collect();
+            let merged = if matches!(copies[0].1, ParsedItem::Module(_)) {
+                let modules = copies
+                    .into_iter()
+                    .filter_map(|(target, item)| match item {
+                        ParsedItem::Module(module) => Some((target, module)),
+                        _ => None,
+                    })
+                    .collect();
+                ParsedItem::Module(merge_modules(modules))
+            } else {
+                copies.into_iter().next().map(|(_, item)| item).expect("every key has a copy")
+            };
+
+            if targets.len() == target_count {
+                merged
+            } else {
+                ParsedItem::Targeted(ParsedTargeted {
+                    targets,
+                    item: Box::new(merged),
+                })
+            }
+        })
+        .collect()
+}
+
+fn item_key(item: &ParsedItem) -> (Discriminant<ParsedItem>, String) {
+    let name = match item {
+        // Impls have no name of their own; the trait and type identify them
+        ParsedItem::TraitImpl(impl_) => format!("{} for {}", impl_.trait_path, impl_.for_type),
+        other => item_name(other).unwrap_or_default().to_string(),
+    };
+    (discriminant(item), name)
+}
@@ -1,5 +1,10 @@
+pub mod filter;
+pub mod merge;
 pub mod types;
+#[allow(clippy::module_inception)]
 pub mod parser;
 
+pub use filter::*;
+pub use merge::*;
 pub use types::*;
 pub use parser::*;
\ No newline at end of file
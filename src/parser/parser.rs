@@ -1,15 +1,131 @@
-use anyhow::Result;
+use crate::error::DoccerError;
 use rustdoc_types::{Crate, Id, Item, ItemEnum, Module, Visibility};
+use std::cell::{OnceCell, RefCell};
+use std::collections::HashSet;
+use tracing::debug;
 use crate::parser::types::*;
 
+type Result<T> = std::result::Result<T, DoccerError>;
+
+// Auto traits summarised by `--auto-traits`, in display order
+const AUTO_TRAITS: [&str; 5] = ["Send", "Sync", "Unpin", "UnwindSafe", "RefUnwindSafe"];
+
 // Parser for converting raw JSON items to typed structures
 pub struct ItemParser<'a> {
     crate_data: &'a Crate,
+    // Items nameable from outside the crate, computed on first use
+    public_items: OnceCell<HashSet<Id>>,
+    // Parse `pub use` of local items into the re-exported item itself
+    inline_reexports: bool,
+    // Spell out types from other crates by their full path in the defining crate
+    qualify_external: bool,
+    // Keep `#[cfg(test)]` items, which only appear when rustdoc ran with `--cfg test`
+    include_tests: bool,
+    // The innermost named item being parsed, to attribute unknown types to
+    current_item: RefCell<Option<String>>,
+    // Types with no supported encoding, in the order they were met
+    unknown_types: RefCell<Vec<UnknownType>>,
 }
 
 impl<'a> ItemParser<'a> {
     pub fn new(crate_data: &'a Crate) -> Self {
-        Self { crate_data }
+        Self {
+            crate_data,
+            public_items: OnceCell::new(),
+            inline_reexports: false,
+            qualify_external: false,
+            include_tests: false,
+            current_item: RefCell::new(None),
+            unknown_types: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Inline local items at the place they are re-exported, noting where they come from
+    pub fn with_inline_reexports(mut self, inline_reexports: bool) -> Self {
+        self.inline_reexports = inline_reexports;
+        self
+    }
+
+    /// Render types from other crates with the path of their origin crate,
+    /// e.g. `std::collections::hash::map::HashMap` instead of `HashMap`
+    pub fn with_qualified_external_paths(mut self, qualify_external: bool) -> Self {
+        self.qualify_external = qualify_external;
+        self
+    }
+
+    /// Keep `#[cfg(test)]` modules and functions instead of dropping them
+    pub fn with_test_items(mut self, include_tests: bool) -> Self {
+        self.include_tests = include_tests;
+        self
+    }
+
+    // The full path of an item defined in another crate, led by that crate's name
+    fn external_path(&self, id: &Id) -> Option<String> {
+        let summary = self.crate_data.paths.get(id)?;
+        let origin = self.crate_data.external_crates.get(&summary.crate_id)?;
+        let (_, rest) = summary.path.split_first()?;
+        let mut segments = vec![origin.name.as_str()];
+        segments.extend(rest.iter().map(String::as_str));
+        Some(segments.join("::"))
+    }
+
+    // Collect every item reachable from the crate root through public modules and re-exports
+    fn public_items(&self) -> &HashSet<Id> {
+        self.public_items.get_or_init(|| {
+            let mut reachable = HashSet::new();
+            let mut queue = vec![self.crate_data.root];
+
+            while let Some(id) = queue.pop() {
+                if !reachable.insert(id) {
+                    continue;
+                }
+                let Some(item) = self.crate_data.index.get(&id) else {
+                    continue;
+                };
+                match &item.inner {
+                    ItemEnum::Module(module) => {
+                        for child_id in &module.items {
+                            let is_public = self
+                                .crate_data
+                                .index
+                                .get(child_id)
+                                .is_some_and(|child| matches!(child.visibility, Visibility::Public));
+                            if is_public {
+                                queue.push(*child_id);
+                            }
+                        }
+                    }
+                    ItemEnum::Use(import) => {
+                        if let Some(target) = import.id {
+                            queue.push(target);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            reachable
+        })
+    }
+
+    // A trait is sealed when one of its supertraits is a local trait that downstream crates cannot name
+    fn is_sealed_trait(&self, trait_data: &serde_json::Value) -> bool {
+        let Some(bounds) = trait_data.get("bounds").and_then(|b| b.as_array()) else {
+            return false;
+        };
+
+        bounds.iter().any(|bound| {
+            let Some(id) = bound
+                .get("trait_bound")
+                .and_then(|b| b.get("trait"))
+                .and_then(|t| t.get("id"))
+                .and_then(|id| id.as_u64())
+            else {
+                return false;
+            };
+            let id = Id(id as u32);
+            self.crate_data.index.contains_key(&id) && !self.public_items().contains(&id)
+        })
     }
 
     // Helper method to check if a trait implementation should be filtered out
@@ -67,6 +183,28 @@ impl<'a> ItemParser<'a> {
         false
     }
 
+    /// Collect the auto traits a type implements from its (normally filtered) impls
+    fn collect_auto_traits(&self, impls: Option<&serde_json::Value>) -> Vec<String> {
+        let implemented: Vec<String> = impls
+            .and_then(|v| v.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|impl_id| impl_id.as_u64())
+            .filter_map(|impl_id| self.crate_data.index.get(&Id(impl_id as u32)))
+            .filter_map(|impl_item| match &impl_item.inner {
+                ItemEnum::Impl(impl_inner) if !impl_inner.is_negative => impl_inner.trait_.as_ref(),
+                _ => None,
+            })
+            .map(|trait_path| trait_path.path.split("::").last().unwrap_or(&trait_path.path).to_string())
+            .collect();
+
+        AUTO_TRAITS
+            .iter()
+            .filter(|name| implemented.iter().any(|t| t == *name))
+            .map(|name| name.to_string())
+            .collect()
+    }
+
     pub fn parse_crate(&self) -> Result<ParsedModule> {
         let root_id = &self.crate_data.root;
         if let Some(root_item) = self.crate_data.index.get(root_id) {
@@ -74,7 +212,8 @@ impl<'a> ItemParser<'a> {
                 name: root_item.name.as_deref().unwrap_or("unknown").to_string(),
                 visibility: Visibility::Public,
                 items: Vec::new(),
-                docs: root_item.docs.clone(),
+                docs: clean_docs(root_item.docs.as_deref()),
+                attrs: root_item.attrs.iter().map(|attr| normalize_attr(attr)).collect(),
             };
 
             if let ItemEnum::Module(module) = &root_item.inner {
@@ -87,16 +226,34 @@ impl<'a> ItemParser<'a> {
 
             Ok(parsed_module)
         } else {
-            Err(anyhow::anyhow!("Root module not found"))
+            Err(DoccerError::Parse("Root module not found".to_string()))
         }
     }
 
+    /// Types met so far that rendered as `...` because their encoding isn't supported
+    pub fn unknown_types(&self) -> Vec<UnknownType> {
+        self.unknown_types.borrow().clone()
+    }
+
     fn parse_item(&self, item_id: &Id) -> Result<Option<ParsedItem>> {
         let item = match self.crate_data.index.get(item_id) {
             Some(item) => item,
             None => return Ok(None),
         };
+        if !self.include_tests && parse_cfg_attrs(&item.attrs).iter().any(|p| p == "test") {
+            return Ok(None);
+        }
+
+        let Some(name) = &item.name else {
+            return self.parse_item_kind(item);
+        };
+        let outer = self.current_item.replace(Some(name.clone()));
+        let parsed = self.parse_item_kind(item);
+        self.current_item.replace(outer);
+        parsed
+    }
 
+    fn parse_item_kind(&self, item: &Item) -> Result<Option<ParsedItem>> {
         match &item.inner {
             ItemEnum::Function(func_data) => {
                 let json_value = serde_json::to_value(func_data)?;
@@ -131,6 +288,18 @@ impl<'a> ItemParser<'a> {
                     return Ok(Some(ParsedItem::Constant(parsed)));
                 }
             }
+            ItemEnum::Static(static_data) => {
+                let json_value = serde_json::to_value(static_data)?;
+                if let Some(parsed) = self.parse_static(item, &json_value)? {
+                    return Ok(Some(ParsedItem::Static(parsed)));
+                }
+            }
+            ItemEnum::TypeAlias(alias_data) => {
+                let json_value = serde_json::to_value(alias_data)?;
+                if let Some(parsed) = self.parse_type_alias(item, &json_value)? {
+                    return Ok(Some(ParsedItem::TypeAlias(parsed)));
+                }
+            }
             ItemEnum::Module(module_data) => {
                 let json_value = serde_json::to_value(module_data)?;
                 if let Some(parsed) = self.parse_module(item, &json_value)? {
@@ -149,17 +318,47 @@ impl<'a> ItemParser<'a> {
                     return Ok(Some(ParsedItem::TraitImpl(parsed)));
                 }
             }
-            // ItemEnum::Import(import_data) => {
-            //     if let Some(parsed) = self.parse_use(item, import_data)? {
-            //         return Ok(Some(ParsedItem::ReExport(parsed)));
-            //     }
-            // }
+            ItemEnum::Use(import) if self.inline_reexports && !import.is_glob => {
+                if let Some(parsed) = self.parse_reexport(item, import)? {
+                    return Ok(Some(ParsedItem::ReExport(parsed)));
+                }
+            }
             _ => {} // Skip other kinds for now
         }
 
         Ok(None)
     }
 
+    fn parse_reexport(
+        &self,
+        use_item: &Item,
+        import: &rustdoc_types::Use,
+    ) -> Result<Option<ParsedReExport>> {
+        // Re-exports of other crates' items have nothing in the index to inline
+        let Some(target_id) = import.id.filter(|id| self.crate_data.index.contains_key(id)) else {
+            return Ok(None);
+        };
+        let Some(item) = self.parse_item(&target_id)? else {
+            return Ok(None);
+        };
+
+        let mut source = self
+            .crate_data
+            .paths
+            .get(&target_id)
+            .map(|summary| summary.path.join("::"))
+            .unwrap_or_else(|| import.source.clone());
+        let defined_name = self.crate_data.index[&target_id].name.as_deref();
+        if defined_name.is_some_and(|name| name != import.name) {
+            source.push_str(&format!(" as {}", import.name));
+        }
+
+        Ok(Some(ParsedReExport {
+            source,
+            docs: clean_docs(use_item.docs.as_deref()),
+            item: Box::new(item),
+        }))
+    }
 
     fn parse_type(&self, type_val: &serde_json::Value) -> RustType {
         // Handle null values as unit type
@@ -186,35 +385,26 @@ impl<'a> ItemParser<'a> {
                 .unwrap_or("unknown")
                 .to_string();
 
-            // Normalize $crate:: paths to their standard library equivalents
-            let normalized_path = if path.starts_with("$crate::") {
-                match path.as_str() {
-                    "$crate::fmt::Formatter" => "std::fmt::Formatter".to_string(),
-                    "$crate::fmt::Result" => "std::fmt::Result".to_string(),
-                    "$crate::clone::Clone" => "Clone".to_string(),
-                    "$crate::cmp::PartialEq" => "PartialEq".to_string(),
-                    other => {
-                        // For other $crate:: paths, replace with std::
-                        other.replace("$crate::", "std::")
-                    }
-                }
-            } else {
-                path
-            };
-
-            let mut generics = Vec::new();
-            if let Some(args) = resolved_path.get("args") {
-                if let Some(angle_bracketed) = args.get("angle_bracketed") {
-                    if let Some(args_array) = angle_bracketed.get("args").and_then(|a| a.as_array())
-                    {
-                        for arg in args_array {
-                            if let Some(type_arg) = arg.get("type") {
-                                generics.push(self.parse_type(type_arg));
-                            }
-                        }
-                    }
-                }
+            let normalized_path = normalize_crate_path(&path);
+            // Primitives spelled through their module, e.g. `std::primitive::u32`
+            if let Some(name) = ["std::primitive::", "core::primitive::"]
+                .iter()
+                .find_map(|prefix| normalized_path.strip_prefix(prefix))
+            {
+                return RustType::Primitive(name.to_string());
             }
+            let normalized_path = resolved_path
+                .get("id")
+                .and_then(|id| id.as_u64())
+                .filter(|_| self.qualify_external)
+                .and_then(|id| self.external_path(&Id(id as u32)))
+                .unwrap_or(normalized_path);
+
+            let generics = resolved_path
+                .get("args")
+                .and_then(|args| args.get("angle_bracketed"))
+                .map(|angle_bracketed| self.parse_generic_args(angle_bracketed))
+                .unwrap_or_default();
 
             return RustType::Path { path: normalized_path, generics };
         }
@@ -263,8 +453,9 @@ impl<'a> ItemParser<'a> {
                 let inner = Box::new(self.parse_type(type_info));
                 let size = array
                     .get("len")
-                    .map(|l| l.to_string())
-                    .unwrap_or_else(|| "N".to_string());
+                    .and_then(|l| l.as_str())
+                    .unwrap_or("_")
+                    .to_string();
                 return RustType::Array { inner, size };
             }
         }
@@ -283,8 +474,12 @@ impl<'a> ItemParser<'a> {
 
         if let Some(qualified_path) = type_val.get("qualified_path") {
             if let Some(name) = qualified_path.get("name").and_then(|n| n.as_str()) {
+                let base = qualified_path
+                    .get("self_type")
+                    .map(|self_type| self.parse_type(self_type).to_string())
+                    .unwrap_or_else(|| "Self".to_string());
                 return RustType::QualifiedPath {
-                    base: "Self".to_string(),
+                    base,
                     name: name.to_string(),
                 };
             }
@@ -296,51 +491,123 @@ impl<'a> ItemParser<'a> {
                 .and_then(|l| l.as_str())
                 .map(|s| s.to_string());
             
-            let mut traits = Vec::new();
-            if let Some(traits_array) = dyn_trait.get("traits").and_then(|t| t.as_array()) {
-                for trait_item in traits_array {
-                    if let Some(trait_info) = trait_item.get("trait") {
-                        if let Some(path) = trait_info.get("path").and_then(|p| p.as_str()) {
-                            let mut trait_str = path.to_string();
-                            
-                            // Handle associated type constraints
-                            if let Some(args) = trait_info.get("args") {
-                                if let Some(angle_bracketed) = args.get("angle_bracketed") {
-                                    if let Some(constraints) = angle_bracketed.get("constraints").and_then(|c| c.as_array()) {
-                                        if !constraints.is_empty() {
-                                            let mut constraint_strs = Vec::new();
-                                            for constraint in constraints {
-                                                if let Some(name) = constraint.get("name").and_then(|n| n.as_str()) {
-                                                    if let Some(binding) = constraint.get("binding") {
-                                                        if let Some(equality) = binding.get("equality") {
-                                                            if let Some(ty) = equality.get("type") {
-                                                                let constraint_type = self.parse_type(ty);
-                                                                constraint_strs.push(format!("{} = {}", name, constraint_type));
-                                                            }
-                                                        }
-                                                    }
-                                                }
-                                            }
-                                            if !constraint_strs.is_empty() {
-                                                trait_str.push_str(&format!("<{}>", constraint_strs.join(", ")));
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                            
-                            traits.push(trait_str);
-                        }
-                    }
-                }
-            }
-            
+            let traits = dyn_trait
+                .get("traits")
+                .and_then(|t| t.as_array())
+                .map(|traits| traits.iter().filter_map(|t| self.parse_poly_trait(t)).collect())
+                .unwrap_or_default();
+
             return RustType::DynTrait { traits, lifetime };
         }
 
+        if let Some(bounds) = type_val.get("impl_trait").and_then(|b| b.as_array()) {
+            return RustType::ImplTrait(bounds.iter().filter_map(|b| self.parse_bound(b)).collect());
+        }
+
+        debug!("Unsupported type encoding: {}", type_val);
+        self.unknown_types.borrow_mut().push(UnknownType {
+            item: self.current_item.borrow().clone().unwrap_or_default(),
+            json: type_val.to_string(),
+        });
         RustType::Unknown
     }
 
+    // The `<'a, T, 3, _>` arguments of a path, in order
+    fn parse_generic_args(&self, angle_bracketed: &serde_json::Value) -> Vec<RustType> {
+        let mut generics = Vec::new();
+        for arg in angle_bracketed.get("args").and_then(|a| a.as_array()).into_iter().flatten() {
+            if let Some(type_arg) = arg.get("type") {
+                generics.push(self.parse_type(type_arg));
+            } else if let Some(lifetime) = arg.get("lifetime").and_then(|l| l.as_str()) {
+                generics.push(RustType::Lifetime(lifetime.to_string()));
+            } else if let Some(constant) = arg.get("const") {
+                let expr = constant.get("expr").and_then(|e| e.as_str()).unwrap_or("_");
+                generics.push(RustType::Const(expr.to_string()));
+            } else if arg.as_str() == Some("infer") {
+                generics.push(RustType::Generic("_".to_string()));
+            }
+        }
+        generics
+    }
+
+    // A generic or `impl Trait` bound: a trait reference or an outlived lifetime
+    fn parse_bound(&self, bound: &serde_json::Value) -> Option<String> {
+        if let Some(trait_bound) = bound.get("trait_bound") {
+            let trait_str = self.parse_poly_trait(trait_bound)?;
+            // Relaxed bounds keep their marker, e.g. `?Sized` or `~const Drop`
+            return Some(match trait_bound.get("modifier").and_then(|m| m.as_str()) {
+                Some("maybe") => format!("?{}", trait_str),
+                Some("maybe_const") => format!("~const {}", trait_str),
+                _ => trait_str,
+            });
+        }
+        bound
+            .get("outlives")
+            .and_then(|l| l.as_str())
+            .map(|l| l.to_string())
+    }
+
+    // A trait reference with its `for<'a>` binder and arguments, e.g.
+    // `for<'a> Fn(&'a str) -> bool` or `Iterator<Item = u8>`
+    fn parse_poly_trait(&self, poly_trait: &serde_json::Value) -> Option<String> {
+        let trait_info = poly_trait.get("trait")?;
+        let mut trait_str = String::new();
+
+        let binder: Vec<&str> = poly_trait
+            .get("generic_params")
+            .and_then(|p| p.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|param| param.get("name").and_then(|n| n.as_str()))
+            .collect();
+        if !binder.is_empty() {
+            trait_str.push_str(&format!("for<{}> ", binder.join(", ")));
+        }
+        // Derive-generated bounds name their traits through `$crate::`
+        trait_str.push_str(&normalize_crate_path(trait_info.get("path")?.as_str()?));
+
+        let args = trait_info.get("args");
+        if let Some(parenthesized) = args.and_then(|a| a.get("parenthesized")) {
+            // Closure traits: `Fn(A, B) -> R`
+            let inputs: Vec<String> = parenthesized
+                .get("inputs")
+                .and_then(|i| i.as_array())
+                .into_iter()
+                .flatten()
+                .map(|input| self.parse_type(input).to_string())
+                .collect();
+            trait_str.push_str(&format!("({})", inputs.join(", ")));
+            if let Some(output) = parenthesized.get("output").filter(|o| !o.is_null()) {
+                trait_str.push_str(&format!(" -> {}", self.parse_type(output)));
+            }
+        } else if let Some(angle_bracketed) = args.and_then(|a| a.get("angle_bracketed")) {
+            // Generic arguments followed by associated type constraints,
+            // e.g. `Borrowing<'a>` or `Future<Output = T>`
+            let mut arg_strs: Vec<String> = self
+                .parse_generic_args(angle_bracketed)
+                .iter()
+                .map(|arg| arg.to_string())
+                .collect();
+            arg_strs.extend(
+                angle_bracketed
+                    .get("constraints")
+                    .and_then(|c| c.as_array())
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|constraint| {
+                        let name = constraint.get("name")?.as_str()?;
+                        let ty = constraint.get("binding")?.get("equality")?.get("type")?;
+                        Some(format!("{} = {}", name, self.parse_type(ty)))
+                    }),
+            );
+            if !arg_strs.is_empty() {
+                trait_str.push_str(&format!("<{}>", arg_strs.join(", ")));
+            }
+        }
+
+        Some(trait_str)
+    }
+
     fn parse_generics(&self, generics: &serde_json::Value) -> Generics {
         let mut params = Vec::new();
         let mut where_clauses = Vec::new();
@@ -350,30 +617,50 @@ impl<'a> ItemParser<'a> {
                 if let Some(name) = param.get("name").and_then(|n| n.as_str()) {
                     if let Some(kind) = param.get("kind") {
                         if let Some(type_kind) = kind.get("type") {
+                            // `impl Trait` arguments show up as synthetic parameters; the
+                            // argument type already spells them out
+                            if type_kind.get("is_synthetic").and_then(|s| s.as_bool()) == Some(true) {
+                                continue;
+                            }
                             let mut bounds = Vec::new();
                             
                             // Parse bounds from the type kind
                             if let Some(bounds_array) = type_kind.get("bounds").and_then(|b| b.as_array()) {
-                                for bound in bounds_array {
-                                    if let Some(trait_bound) = bound.get("trait_bound") {
-                                        if let Some(trait_ref) = trait_bound.get("trait") {
-                                            if let Some(path) = trait_ref.get("path").and_then(|p| p.as_str()) {
-                                                bounds.push(path.to_string());
-                                            }
-                                        }
+                                // A derive repeats the type's own bounds, e.g. `T: Copy + Copy`
+                                for bound in bounds_array.iter().filter_map(|b| self.parse_bound(b)) {
+                                    if !bounds.contains(&bound) {
+                                        bounds.push(bound);
                                     }
                                 }
                             }
                             
+                            let default = type_kind
+                                .get("default")
+                                .filter(|d| !d.is_null())
+                                .map(|d| self.parse_type(d));
+
                             params.push(GenericParam {
                                 name: name.to_string(),
-                                kind: GenericParamKind::Type { bounds },
+                                kind: GenericParamKind::Type { bounds, default },
                             });
                         } else if kind.get("lifetime").is_some() {
                             params.push(GenericParam {
                                 name: name.to_string(),
                                 kind: GenericParamKind::Lifetime,
                             });
+                        } else if let Some(const_kind) = kind.get("const") {
+                            let ty = const_kind
+                                .get("type")
+                                .map(|t| self.parse_type(t))
+                                .unwrap_or(RustType::Unknown);
+                            let default = const_kind
+                                .get("default")
+                                .and_then(|d| d.as_str())
+                                .map(|d| d.to_string());
+                            params.push(GenericParam {
+                                name: name.to_string(),
+                                kind: GenericParamKind::Const { ty, default },
+                            });
                         }
                     }
                 }
@@ -385,33 +672,41 @@ impl<'a> ItemParser<'a> {
             for predicate in where_predicates {
                 if let Some(bound_predicate) = predicate.get("bound_predicate") {
                     if let Some(type_info) = bound_predicate.get("type") {
-                        // Get the type being constrained
-                        let type_name = if let Some(generic_name) = type_info.get("generic").and_then(|g| g.as_str()) {
-                            generic_name.to_string()
-                        } else {
-                            // For more complex types, we'd need to parse them fully
-                            "Self".to_string()
-                        };
+                        // Get the type being constrained: `Self`, `T`, or a full
+                        // type such as `Vec<T>` or `T::Item`
+                        let type_name = self.parse_type(type_info).to_string();
                         
                         // Parse the bounds
                         let mut bounds = Vec::new();
                         if let Some(bounds_array) = bound_predicate.get("bounds").and_then(|b| b.as_array()) {
-                            for bound in bounds_array {
-                                if let Some(trait_bound) = bound.get("trait_bound") {
-                                    if let Some(trait_ref) = trait_bound.get("trait") {
-                                        if let Some(path) = trait_ref.get("path").and_then(|p| p.as_str()) {
-                                            bounds.push(path.to_string());
-                                        }
-                                    }
-                                }
-                            }
+                            bounds.extend(bounds_array.iter().filter_map(|b| self.parse_bound(b)));
                         }
                         
                         if !bounds.is_empty() {
-                            let where_clause = format!("{}: {}", type_name, bounds.join(" + "));
-                            where_clauses.push(where_clause);
+                            where_clauses.push(bounded(&type_name, &bounds));
                         }
                     }
+                } else if let Some(lifetime_predicate) = predicate.get("lifetime_predicate") {
+                    // `'a: 'b` predicates
+                    let lifetime = lifetime_predicate.get("lifetime").and_then(|l| l.as_str());
+                    let outlives: Vec<&str> = lifetime_predicate
+                        .get("outlives")
+                        .and_then(|o| o.as_array())
+                        .map(|o| o.iter().filter_map(|l| l.as_str()).collect())
+                        .unwrap_or_default();
+                    if let (Some(lifetime), false) = (lifetime, outlives.is_empty()) {
+                        where_clauses.push(bounded(lifetime, &outlives));
+                    }
+                } else if let Some(eq_predicate) = predicate.get("eq_predicate") {
+                    // `<T as Iterator>::Item = u32` predicates
+                    let rhs = eq_predicate.get("rhs").and_then(|rhs| {
+                        rhs.get("type")
+                            .map(|ty| self.parse_type(ty).to_string())
+                            .or_else(|| rhs.get("constant")?.get("expr")?.as_str().map(str::to_string))
+                    });
+                    if let (Some(lhs), Some(rhs)) = (eq_predicate.get("lhs"), rhs) {
+                        where_clauses.push(format!("{} = {}", self.parse_projection(lhs), rhs));
+                    }
                 }
             }
         }
@@ -422,6 +717,24 @@ impl<'a> ItemParser<'a> {
         }
     }
 
+    // Spell out the trait of an associated type projection, e.g. `<T as Iterator>::Item`
+    fn parse_projection(&self, type_val: &serde_json::Value) -> String {
+        let projection = type_val.get("qualified_path");
+        let trait_path = projection
+            .and_then(|q| q.get("trait"))
+            .and_then(|t| t.get("path"))
+            .and_then(|p| p.as_str());
+        match (projection, trait_path) {
+            (Some(projection), Some(trait_path)) => format!(
+                "<{} as {}>::{}",
+                self.parse_type(projection.get("self_type").unwrap_or(&serde_json::Value::Null)),
+                trait_path,
+                projection.get("name").and_then(|n| n.as_str()).unwrap_or_default()
+            ),
+            _ => self.parse_type(type_val).to_string(),
+        }
+    }
+
     fn parse_function(
         &self,
         item: &Item,
@@ -430,7 +743,7 @@ impl<'a> ItemParser<'a> {
         let name = item
             .name
             .as_ref()
-            .ok_or_else(|| anyhow::anyhow!("Function missing name"))?
+            .ok_or_else(|| DoccerError::Parse("Function missing name".to_string()))?
             .clone();
         let visibility = item.visibility.clone();
         let generics = func_data
@@ -448,13 +761,9 @@ impl<'a> ItemParser<'a> {
             if let Some(inputs_val) = sig.get("inputs") {
                 if let Some(inputs_array) = inputs_val.as_array() {
                     for input in inputs_array {
-                        if let Some(input_array) = input.as_array() {
-                            if input_array.len() == 2 {
-                                if let Some(param_name) = input_array[0].as_str() {
-                                    let param_type = self.parse_type(&input_array[1]);
-                                    inputs.push((param_name.to_string(), param_type));
-                                }
-                            }
+                        match self.parse_input(input) {
+                            Some(parsed) => inputs.push(parsed),
+                            None => debug!("Skipping unrecognized input shape in {}: {}", name, input),
                         }
                     }
                 }
@@ -466,10 +775,12 @@ impl<'a> ItemParser<'a> {
         }
 
         let mut is_async = false;
+        let mut abi = None;
         if let Some(header) = func_data.get("header") {
             if let Some(async_flag) = header.get("is_async") {
                 is_async = async_flag.as_bool().unwrap_or(false);
             }
+            abi = header.get("abi").and_then(|a| self.parse_abi(a));
         }
 
         let signature = FunctionSignature {
@@ -479,15 +790,62 @@ impl<'a> ItemParser<'a> {
             inputs,
             output,
             is_async,
+            abi,
         };
 
         Ok(Some(ParsedFunction {
             signature,
-            docs: item.docs.clone(),
+            docs: clean_docs(item.docs.as_deref()),
             deprecation: item.deprecation.clone(),
+            aliases: parse_doc_aliases(&item.attrs),
+            attrs: item.attrs.iter().map(|attr| normalize_attr(attr)).collect(),
         }))
     }
 
+    // Parse one function input, accepting both the `[name, type]` pair used by
+    // current format versions and a `{"pattern": name, "type": type}` object
+    pub(crate) fn parse_input(&self, input: &serde_json::Value) -> Option<(String, RustType)> {
+        let (name, ty) = match input {
+            serde_json::Value::Array(pair) if pair.len() == 2 => (&pair[0], &pair[1]),
+            serde_json::Value::Object(fields) => {
+                (fields.get("pattern").or_else(|| fields.get("name"))?, fields.get("type")?)
+            }
+            _ => return None,
+        };
+        Some((name.as_str()?.to_string(), self.parse_type(ty)))
+    }
+
+    // Convert rustdoc's ABI encoding back to the string written in `extern "..."`
+    fn parse_abi(&self, abi: &serde_json::Value) -> Option<String> {
+        if let Some(name) = abi.as_str() {
+            // Unit variants serialize as bare strings; only `Rust` exists today
+            return if name == "Rust" { None } else { Some(name.to_string()) };
+        }
+
+        let (variant, data) = abi.as_object()?.iter().next()?;
+        if variant == "Other" {
+            return data.as_str().map(|other| other.trim_matches('"').to_string());
+        }
+
+        let base = match variant.as_str() {
+            "C" => "C",
+            "Cdecl" => "cdecl",
+            "Stdcall" => "stdcall",
+            "Fastcall" => "fastcall",
+            "Aapcs" => "aapcs",
+            "Win64" => "win64",
+            "SysV64" => "sysv64",
+            "System" => "system",
+            other => other,
+        };
+        let unwind = data.get("unwind").and_then(|u| u.as_bool()).unwrap_or(false);
+        Some(if unwind {
+            format!("{}-unwind", base)
+        } else {
+            base.to_string()
+        })
+    }
+
     fn parse_struct(
         &self,
         item: &Item,
@@ -496,7 +854,7 @@ impl<'a> ItemParser<'a> {
         let name = item
             .name
             .as_ref()
-            .ok_or_else(|| anyhow::anyhow!("Struct missing name"))?
+            .ok_or_else(|| DoccerError::Parse("Struct missing name".to_string()))?
             .clone();
         let visibility = item.visibility.clone();
         let generics = struct_data
@@ -508,6 +866,9 @@ impl<'a> ItemParser<'a> {
             });
 
         let mut methods = Vec::new();
+        let mut assoc_types = Vec::new();
+        let mut consts = Vec::new();
+        let mut inherent_impls = Vec::new();
         let mut trait_impls = Vec::new();
         let mut fields = Vec::new();
 
@@ -526,7 +887,7 @@ impl<'a> ItemParser<'a> {
                                             name: field_name,
                                             visibility: field_item.visibility.clone(),
                                             field_type: self.parse_type(&serde_json::to_value(field_type).unwrap_or_default()),
-                                            docs: field_item.docs.clone(),
+                                            docs: clean_docs(field_item.docs.as_deref()),
                                             deprecation: field_item.deprecation.clone(),
                                         };
                                         fields.push(parsed_field);
@@ -550,24 +911,58 @@ impl<'a> ItemParser<'a> {
                                 let is_trait_impl = impl_inner.trait_.is_some();
 
                                 if !is_trait_impl {
-                                    // Inherent impl - collect methods
+                                    // Inherent impl - collect methods and associated items
+                                    let mut impl_methods = Vec::new();
+                                    let mut impl_assoc_types = Vec::new();
+                                    let mut impl_consts = Vec::new();
                                     for method_id in &impl_inner.items {
-                                        if let Some(method_item) =
-                                            self.crate_data.index.get(method_id)
-                                        {
-                                            if let ItemEnum::Function(func_data) =
-                                                &method_item.inner
-                                            {
+                                        let Some(method_item) = self.crate_data.index.get(method_id)
+                                        else {
+                                            continue;
+                                        };
+                                        match &method_item.inner {
+                                            ItemEnum::Function(func_data) => {
                                                 // Convert Function enum back to JSON for now
                                                 let func_json = serde_json::to_value(func_data)?;
                                                 if let Some(parsed_method) =
                                                     self.parse_function(method_item, &func_json)?
                                                 {
-                                                    methods.push(parsed_method);
+                                                    impl_methods.push(parsed_method);
                                                 }
                                             }
+                                            ItemEnum::AssocType { type_: Some(ty), .. } => {
+                                                impl_assoc_types.push(ParsedAssocType {
+                                                    name: method_item.name.clone().unwrap_or_default(),
+                                                    visibility: method_item.visibility.clone(),
+                                                    ty: self.parse_type(&serde_json::to_value(ty)?),
+                                                    docs: clean_docs(method_item.docs.as_deref()),
+                                                });
+                                            }
+                                            ItemEnum::AssocConst { type_, value } => {
+                                                impl_consts.push(ParsedConstant {
+                                                    name: method_item.name.clone().unwrap_or_default(),
+                                                    visibility: method_item.visibility.clone(),
+                                                    ty: self.parse_type(&serde_json::to_value(type_)?),
+                                                    value: value.clone(),
+                                                    docs: clean_docs(method_item.docs.as_deref()),
+                                                    deprecation: method_item.deprecation.clone(),
+                                                    aliases: parse_doc_aliases(&method_item.attrs),
+                                                });
+                                            }
+                                            _ => {}
                                         }
                                     }
+                                    methods.extend(impl_methods.iter().cloned());
+                                    assoc_types.extend(impl_assoc_types.iter().cloned());
+                                    consts.extend(impl_consts.iter().cloned());
+                                    inherent_impls.push(ParsedInherentImpl {
+                                        generics: self.parse_generics(&serde_json::to_value(&impl_inner.generics)?),
+                                        for_type: self.parse_type(&serde_json::to_value(&impl_inner.for_)?),
+                                        assoc_types: impl_assoc_types,
+                                        consts: impl_consts,
+                                        methods: impl_methods,
+                                        docs: clean_docs(impl_item.docs.as_deref()),
+                                    });
                                 } else {
                                     // Trait impl - collect it only if it should not be filtered
                                     let impl_json = serde_json::to_value(impl_inner)?;
@@ -594,11 +989,16 @@ impl<'a> ItemParser<'a> {
             name,
             visibility,
             generics,
-            docs: item.docs.clone(),
+            docs: clean_docs(item.docs.as_deref()),
             deprecation: item.deprecation.clone(),
+            aliases: parse_doc_aliases(&item.attrs),
             fields,
             methods,
+            assoc_types,
+            consts,
+            inherent_impls,
             trait_impls,
+            auto_traits: self.collect_auto_traits(struct_data.get("impls")),
         }))
     }
 
@@ -606,7 +1006,7 @@ impl<'a> ItemParser<'a> {
         let name = item
             .name
             .as_ref()
-            .ok_or_else(|| anyhow::anyhow!("Enum missing name"))?
+            .ok_or_else(|| DoccerError::Parse("Enum missing name".to_string()))?
             .clone();
         let visibility = item.visibility.clone();
         let generics = enum_data
@@ -624,7 +1024,11 @@ impl<'a> ItemParser<'a> {
                 if let Some(variant_id_num) = variant_id.as_u64() {
                     let variant_id = Id(variant_id_num as u32);
                     if let Some(variant_item) = self.crate_data.index.get(&variant_id) {
-                        if let Some(parsed_variant) = self.parse_variant(variant_item)? {
+                        if let Some(mut parsed_variant) = self.parse_variant(variant_item)? {
+                            // rustdoc copies the enum's own deprecation onto each variant
+                            if parsed_variant.deprecation == item.deprecation {
+                                parsed_variant.deprecation = None;
+                            }
                             variants.push(parsed_variant);
                         }
                     }
@@ -637,8 +1041,10 @@ impl<'a> ItemParser<'a> {
             visibility,
             generics,
             variants,
-            docs: item.docs.clone(),
+            docs: clean_docs(item.docs.as_deref()),
             deprecation: item.deprecation.clone(),
+            aliases: parse_doc_aliases(&item.attrs),
+            auto_traits: self.collect_auto_traits(enum_data.get("impls")),
         }))
     }
 
@@ -646,41 +1052,26 @@ impl<'a> ItemParser<'a> {
         let name = item
             .name
             .as_ref()
-            .ok_or_else(|| anyhow::anyhow!("Variant missing name"))?
+            .ok_or_else(|| DoccerError::Parse("Variant missing name".to_string()))?
             .clone();
 
         let kind = if let ItemEnum::Variant(variant_data) = &item.inner {
             match &variant_data.kind {
                 rustdoc_types::VariantKind::Plain => VariantKind::Unit,
                 rustdoc_types::VariantKind::Tuple(tuple_fields) => {
-                    let mut field_types = Vec::new();
-                    for field_id_opt in tuple_fields {
-                        if let Some(field_id) = field_id_opt {
-                            if let Some(field_item) = self.crate_data.index.get(field_id) {
-                                if let ItemEnum::StructField(field_data) = &field_item.inner {
-                                    let field_json = serde_json::to_value(field_data)?;
-                                    let field_type = self.parse_type(&field_json);
-                                    field_types.push(field_type);
-                                }
-                            }
+                    let mut tuple_field_list = Vec::new();
+                    for field_id in tuple_fields.iter().flatten() {
+                        if let Some(field) = self.parse_variant_field(field_id)? {
+                            tuple_field_list.push(field);
                         }
                     }
-                    VariantKind::Tuple(field_types)
+                    VariantKind::Tuple(tuple_field_list)
                 }
                 rustdoc_types::VariantKind::Struct { fields, .. } => {
                     let mut named_fields = Vec::new();
                     for field_id in fields {
-                        if let Some(field_item) = self.crate_data.index.get(field_id) {
-                            if let ItemEnum::StructField(field_data) = &field_item.inner {
-                                let field_name = field_item
-                                    .name
-                                    .as_ref()
-                                    .unwrap_or(&"unknown".to_string())
-                                    .clone();
-                                let field_json = serde_json::to_value(field_data)?;
-                                let field_type = self.parse_type(&field_json);
-                                named_fields.push((field_name, field_type));
-                            }
+                        if let Some(field) = self.parse_variant_field(field_id)? {
+                            named_fields.push(field);
                         }
                     }
                     VariantKind::Struct(named_fields)
@@ -693,7 +1084,33 @@ impl<'a> ItemParser<'a> {
         Ok(Some(ParsedVariant {
             name,
             kind,
-            docs: item.docs.clone(),
+            docs: clean_docs(item.docs.as_deref()),
+            deprecation: item.deprecation.clone(),
+            non_exhaustive: item
+                .attrs
+                .iter()
+                .any(|attr| attr == "#[non_exhaustive]" || attr == "#[attr = NonExhaustive]"),
+        }))
+    }
+
+    fn parse_variant_field(&self, field_id: &Id) -> Result<Option<ParsedField>> {
+        let Some(field_item) = self.crate_data.index.get(field_id) else {
+            return Ok(None);
+        };
+        let ItemEnum::StructField(field_data) = &field_item.inner else {
+            return Ok(None);
+        };
+
+        let field_json = serde_json::to_value(field_data)?;
+        Ok(Some(ParsedField {
+            name: field_item
+                .name
+                .clone()
+                .unwrap_or_else(|| "unknown".to_string()),
+            visibility: field_item.visibility.clone(),
+            field_type: self.parse_type(&field_json),
+            docs: clean_docs(field_item.docs.as_deref()),
+            deprecation: field_item.deprecation.clone(),
         }))
     }
 
@@ -705,7 +1122,7 @@ impl<'a> ItemParser<'a> {
         let name = item
             .name
             .as_ref()
-            .ok_or_else(|| anyhow::anyhow!("Trait missing name"))?
+            .ok_or_else(|| DoccerError::Parse("Trait missing name".to_string()))?
             .clone();
         let visibility = item.visibility.clone();
         let generics = trait_data
@@ -736,20 +1153,37 @@ impl<'a> ItemParser<'a> {
             visibility,
             generics,
             items,
-            docs: item.docs.clone(),
+            is_unsafe: trait_data
+                .get("is_unsafe")
+                .and_then(|u| u.as_bool())
+                .unwrap_or(false),
+            is_sealed: self.is_sealed_trait(trait_data),
+            docs: clean_docs(item.docs.as_deref()),
             deprecation: item.deprecation.clone(),
+            aliases: parse_doc_aliases(&item.attrs),
         }))
     }
 
     fn parse_trait_item(&self, item: &Item) -> Result<Option<ParsedTraitItem>> {
         match &item.inner {
-            ItemEnum::AssocType { .. } => {
+            ItemEnum::AssocType { bounds, type_, .. } => {
                 let name = item.name.as_ref().unwrap_or(&"unknown".to_string()).clone();
-                let bounds = Vec::new(); // TODO: Parse bounds
+                let bounds_json = serde_json::to_value(bounds)?;
+                let bounds = bounds_json
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|b| self.parse_bound(b))
+                    .collect();
+                let default = match type_ {
+                    Some(ty) => Some(self.parse_type(&serde_json::to_value(ty)?)),
+                    None => None,
+                };
                 return Ok(Some(ParsedTraitItem::AssocType {
                     name,
                     bounds,
-                    docs: item.docs.clone(),
+                    default,
+                    docs: clean_docs(item.docs.as_deref()),
                 }));
             }
             ItemEnum::Function(func_data) => {
@@ -765,7 +1199,7 @@ impl<'a> ItemParser<'a> {
                 return Ok(Some(ParsedTraitItem::AssocConst {
                     name,
                     ty,
-                    docs: item.docs.clone(),
+                    docs: clean_docs(item.docs.as_deref()),
                 }));
             }
             _ => {}
@@ -781,7 +1215,7 @@ impl<'a> ItemParser<'a> {
         let name = item
             .name
             .as_ref()
-            .ok_or_else(|| anyhow::anyhow!("Constant missing name"))?
+            .ok_or_else(|| DoccerError::Parse("Constant missing name".to_string()))?
             .clone();
         let visibility = item.visibility.clone();
         let ty = const_data
@@ -789,12 +1223,90 @@ impl<'a> ItemParser<'a> {
             .map(|t| self.parse_type(t))
             .unwrap_or(RustType::Unknown);
 
+        // Prefer the expression as written; rustdoc elides complex ones as `_`,
+        // in which case fall back to the evaluated value
+        let value = const_data.get("const").and_then(|c| {
+            c.get("expr")
+                .and_then(|e| e.as_str())
+                .filter(|e| !e.is_empty() && *e != "_")
+                .or_else(|| c.get("value").and_then(|v| v.as_str()))
+                .map(|v| v.to_string())
+        });
+
         Ok(Some(ParsedConstant {
             name,
             visibility,
             ty,
-            docs: item.docs.clone(),
+            value,
+            docs: clean_docs(item.docs.as_deref()),
+            deprecation: item.deprecation.clone(),
+            aliases: parse_doc_aliases(&item.attrs),
+        }))
+    }
+
+    fn parse_static(
+        &self,
+        item: &Item,
+        static_data: &serde_json::Value,
+    ) -> Result<Option<ParsedStatic>> {
+        let name = item
+            .name
+            .as_ref()
+            .ok_or_else(|| DoccerError::Parse("Static missing name".to_string()))?
+            .clone();
+        let ty = static_data
+            .get("type")
+            .map(|t| self.parse_type(t))
+            .unwrap_or(RustType::Unknown);
+        let mutable = static_data
+            .get("is_mutable")
+            .and_then(|m| m.as_bool())
+            .unwrap_or(false);
+        let value = static_data
+            .get("expr")
+            .and_then(|e| e.as_str())
+            .filter(|e| !e.is_empty() && *e != "_")
+            .map(|e| e.to_string());
+
+        Ok(Some(ParsedStatic {
+            name,
+            visibility: item.visibility.clone(),
+            ty,
+            mutable,
+            value,
+            docs: clean_docs(item.docs.as_deref()),
+            deprecation: item.deprecation.clone(),
+            aliases: parse_doc_aliases(&item.attrs),
+        }))
+    }
+
+    fn parse_type_alias(
+        &self,
+        item: &Item,
+        alias_data: &serde_json::Value,
+    ) -> Result<Option<ParsedTypeAlias>> {
+        let name = item
+            .name
+            .as_ref()
+            .ok_or_else(|| DoccerError::Parse("Type alias missing name".to_string()))?
+            .clone();
+        let generics = alias_data
+            .get("generics")
+            .map(|g| self.parse_generics(g))
+            .unwrap_or_else(|| Generics { params: Vec::new(), where_clauses: Vec::new() });
+        let ty = alias_data
+            .get("type")
+            .map(|t| self.parse_type(t))
+            .unwrap_or(RustType::Unknown);
+
+        Ok(Some(ParsedTypeAlias {
+            name,
+            visibility: item.visibility.clone(),
+            generics,
+            ty,
+            docs: clean_docs(item.docs.as_deref()),
             deprecation: item.deprecation.clone(),
+            aliases: parse_doc_aliases(&item.attrs),
         }))
     }
 
@@ -819,7 +1331,8 @@ impl<'a> ItemParser<'a> {
             name,
             visibility,
             items,
-            docs: item.docs.clone(),
+            docs: clean_docs(item.docs.as_deref()),
+            attrs: item.attrs.iter().map(|attr| normalize_attr(attr)).collect(),
         }))
     }
 
@@ -831,30 +1344,39 @@ impl<'a> ItemParser<'a> {
         let name = item
             .name
             .as_ref()
-            .ok_or_else(|| anyhow::anyhow!("Macro missing name"))?
+            .ok_or_else(|| DoccerError::Parse("Macro missing name".to_string()))?
             .clone();
 
-        let signature = if let Some(macro_str) = macro_data.as_str() {
-            if let Some(start) = macro_str.find('(') {
-                if let Some(end) = macro_str.find(')') {
-                    let params_part = &macro_str[start + 1..end];
-                    format!("macro_rules! {}({})", name, params_part)
-                } else {
-                    format!("macro_rules! {}(...)", name)
-                }
-            } else {
-                format!("macro_rules! {}", name)
-            }
-        } else {
-            format!("macro_rules! {}", name)
+        // rustdoc hands us the whole definition with each expansion elided to
+        // `{ ... }`, so keep every arm and whatever delimiters the matchers use
+        let signature = match macro_data.as_str() {
+            Some(source) => self.normalize_macro_source(source),
+            None => format!("macro_rules! {} {{ ... }}", name),
         };
 
         Ok(Some(ParsedMacro {
             signature,
-            docs: item.docs.clone(),
+            docs: clean_docs(item.docs.as_deref()),
         }))
     }
 
+    // Trim the source's trailing whitespace and point `$crate::` paths at the
+    // crate being documented, which is what they resolve to for its callers
+    fn normalize_macro_source(&self, source: &str) -> String {
+        let crate_name = self
+            .crate_data
+            .index
+            .get(&self.crate_data.root)
+            .and_then(|root| root.name.as_deref())
+            .unwrap_or("crate");
+        source
+            .trim()
+            .lines()
+            .map(|line| line.trim_end().replace("$crate::", &format!("{}::", crate_name)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     fn parse_trait_impl(
         &self,
         item: &Item,
@@ -874,11 +1396,20 @@ impl<'a> ItemParser<'a> {
                     .unwrap_or(RustType::Unknown);
 
                 let mut items = Vec::new();
+                // Methods written out in this impl, as opposed to trait defaults
+                let mut overridden: HashSet<&str> = HashSet::new();
                 if let Some(impl_items) = impl_data.get("items").and_then(|i| i.as_array()) {
                     for item_id in impl_items {
                         if let Some(item_id_num) = item_id.as_u64() {
                             let item_id = Id(item_id_num as u32);
                             if let Some(impl_item) = self.crate_data.index.get(&item_id) {
+                                if let (ItemEnum::Function(func), Some(name)) =
+                                    (&impl_item.inner, impl_item.name.as_deref())
+                                {
+                                    if func.has_body {
+                                        overridden.insert(name);
+                                    }
+                                }
                                 if let Some(parsed_impl_item) =
                                     self.parse_trait_impl_item(impl_item)?
                                 {
@@ -889,11 +1420,32 @@ impl<'a> ItemParser<'a> {
                     }
                 }
 
+                // Index iteration order is not stable across rustc versions, so
+                // order impl items by (kind, name): associated types, then methods
+                items.sort_by(|a, b| a.sort_key().cmp(&b.sort_key()));
+
+                // `provided_trait_methods` lists every defaulted method, overridden or not
+                let mut inherited = self.collect_inherited_methods(trait_ref, impl_data)?;
+                inherited.retain(|method| !overridden.contains(method.signature.name.as_str()));
+
                 return Ok(Some(ParsedTraitImpl {
                     trait_path,
+                    is_unsafe: impl_data
+                        .get("is_unsafe")
+                        .and_then(|u| u.as_bool())
+                        .unwrap_or(false),
+                    cfg: parse_cfg_attrs(&item.attrs),
+                    generics: impl_data
+                        .get("generics")
+                        .map(|g| self.parse_generics(g))
+                        .unwrap_or_else(|| Generics {
+                            params: Vec::new(),
+                            where_clauses: Vec::new(),
+                        }),
                     for_type,
                     items,
-                    docs: item.docs.clone(),
+                    inherited,
+                    docs: clean_docs(item.docs.as_deref()),
                 }));
             }
         }
@@ -901,6 +1453,50 @@ impl<'a> ItemParser<'a> {
     }
 
 
+    /// Look up the provided methods an impl leaves to the trait's defaults. Only
+    /// traits defined in this crate can be resolved; others yield nothing.
+    fn collect_inherited_methods(
+        &self,
+        trait_ref: &serde_json::Value,
+        impl_data: &serde_json::Value,
+    ) -> Result<Vec<ParsedFunction>> {
+        let provided: Vec<&str> = impl_data
+            .get("provided_trait_methods")
+            .and_then(|p| p.as_array())
+            .map(|names| names.iter().filter_map(|n| n.as_str()).collect())
+            .unwrap_or_default();
+        if provided.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let trait_item = trait_ref
+            .get("id")
+            .and_then(|id| id.as_u64())
+            .and_then(|id| self.crate_data.index.get(&Id(id as u32)));
+        let Some(ItemEnum::Trait(trait_data)) = trait_item.map(|item| &item.inner) else {
+            return Ok(Vec::new());
+        };
+
+        let mut inherited = Vec::new();
+        for method_id in &trait_data.items {
+            let Some(method_item) = self.crate_data.index.get(method_id) else {
+                continue;
+            };
+            let is_provided = method_item
+                .name
+                .as_deref()
+                .is_some_and(|name| provided.contains(&name));
+            if let (true, ItemEnum::Function(func_data)) = (is_provided, &method_item.inner) {
+                let func_json = serde_json::to_value(func_data)?;
+                if let Some(parsed) = self.parse_function(method_item, &func_json)? {
+                    inherited.push(parsed);
+                }
+            }
+        }
+        inherited.sort_by(|a, b| a.signature.name.cmp(&b.signature.name));
+        Ok(inherited)
+    }
+
     fn parse_trait_impl_item(&self, item: &Item) -> Result<Option<ParsedTraitImplItem>> {
         match &item.inner {
             ItemEnum::AssocType { type_, .. } => {
@@ -923,4 +1519,145 @@ impl<'a> ItemParser<'a> {
         }
         Ok(None)
     }
-}
\ No newline at end of file
+}
+
+// Doc text with `\r\n` and stray `\r` line endings turned into `\n`; JSON built from
+// a Windows checkout keeps the carriage returns, which `str::lines` only partly strips
+fn clean_docs(docs: Option<&str>) -> Option<String> {
+    docs.map(|d| d.replace("\r\n", "\n").replace('\r', "\n"))
+}
+
+// Normalize $crate:: paths, as emitted by std's derive macros, to their standard
+// library equivalents, using the bare name for prelude items
+fn normalize_crate_path(path: &str) -> String {
+    let Some(rest) = path.strip_prefix("$crate::") else {
+        return path.to_string();
+    };
+    match rest {
+        "clone::Clone" | "cmp::PartialEq" | "cmp::Eq" | "cmp::PartialOrd" | "cmp::Ord"
+        | "marker::Copy" | "default::Default" => {
+            rest.rsplit("::").next().unwrap_or(rest).to_string()
+        }
+        // For other $crate:: paths, replace with std::
+        _ => format!("std::{}", rest),
+    }
+}
+
+// Collect the predicates of `#[cfg(...)]` attributes. Newer rustdoc only records
+// them in the debug form `#[attr = CfgTrace([...])]`, which is translated back
+fn parse_cfg_attrs(attrs: &[String]) -> Vec<String> {
+    let mut predicates = Vec::new();
+    for attr in attrs {
+        if let Some(predicate) = attr.strip_prefix("#[cfg(").and_then(|a| a.strip_suffix(")]")) {
+            predicates.push(predicate.to_string());
+        } else if let Some(trace) = attr
+            .strip_prefix("#[attr = CfgTrace([")
+            .and_then(|a| a.strip_suffix("])]"))
+        {
+            let mut rest = trace;
+            while let Some(predicate) = parse_cfg_trace_predicate(&mut rest) {
+                predicates.push(predicate);
+                rest = rest.trim_start().trim_start_matches(',');
+            }
+        }
+    }
+    predicates
+}
+
+// Parse one predicate of a `CfgTrace` debug dump, e.g.
+// `NameValue { name: "feature", value: Some("x"), span: .. }` or `Not(NameValue { .. }, span)`
+fn parse_cfg_trace_predicate(input: &mut &str) -> Option<String> {
+    let trimmed = input.trim_start();
+    let kind_len = trimmed.find(|c: char| !c.is_alphanumeric())?;
+    let (kind, rest) = trimmed.split_at(kind_len);
+    *input = rest;
+
+    let predicate = match kind {
+        "NameValue" => {
+            let body = take_delimited(input, '{', '}')?;
+            let name = quoted_after(body, "name: ")?;
+            match quoted_after(body, "value: Some(") {
+                Some(value) => format!("{} = \"{}\"", name, value),
+                None => name.to_string(),
+            }
+        }
+        "All" | "Any" => {
+            let mut args = take_delimited(input, '(', ')')?.trim_start().strip_prefix('[')?;
+            let mut nested = Vec::new();
+            while let Some(predicate) = parse_cfg_trace_predicate(&mut args) {
+                nested.push(predicate);
+                args = args.trim_start().trim_start_matches(',');
+            }
+            format!("{}({})", kind.to_lowercase(), nested.join(", "))
+        }
+        "Not" => {
+            let mut args = take_delimited(input, '(', ')')?;
+            format!("not({})", parse_cfg_trace_predicate(&mut args)?)
+        }
+        _ => return None,
+    };
+    Some(predicate)
+}
+
+// Split off a balanced `open ... close` group at the start of `input`, returning its contents
+fn take_delimited<'a>(input: &mut &'a str, open: char, close: char) -> Option<&'a str> {
+    let trimmed = input.trim_start();
+    if !trimmed.starts_with(open) {
+        return None;
+    }
+    let mut depth = 0;
+    for (i, c) in trimmed.char_indices() {
+        if c == open {
+            depth += 1;
+        } else if c == close {
+            depth -= 1;
+            if depth == 0 {
+                *input = &trimmed[i + 1..];
+                return Some(&trimmed[1..i]);
+            }
+        }
+    }
+    None
+}
+
+// The quoted string directly following `label`, e.g. `name: "unix"`
+fn quoted_after<'a>(text: &'a str, label: &str) -> Option<&'a str> {
+    let start = text.find(label)? + label.len();
+    let quoted = text[start..].strip_prefix('"')?;
+    quoted.find('"').map(|end| &quoted[..end])
+}
+
+// Collect `#[doc(alias = "a")]` and `#[doc(alias("a", "b"))]` names, in order
+fn parse_doc_aliases(attrs: &[String]) -> Vec<String> {
+    let mut aliases = Vec::new();
+    for attr in attrs {
+        let Some(inner) = attr
+            .strip_prefix("#[doc(alias")
+            .and_then(|rest| rest.strip_suffix(")]"))
+        else {
+            continue;
+        };
+        // Either ` = "a"` or `("a", "b")`; the quoted strings are the aliases
+        aliases.extend(
+            inner
+                .split('"')
+                .skip(1)
+                .step_by(2)
+                .map(|alias| alias.to_string()),
+        );
+    }
+    aliases
+}
+
+// Some rustdoc versions print parsed attributes in their internal debug form
+// (`#[attr = Inline(Always)]`); map the common ones back to source syntax
+fn normalize_attr(attr: &str) -> String {
+    match attr {
+        "#[attr = Inline(Hint)]" => "#[inline]".to_string(),
+        "#[attr = Inline(Always)]" => "#[inline(always)]".to_string(),
+        "#[attr = Inline(Never)]" => "#[inline(never)]".to_string(),
+        "#[attr = Cold]" => "#[cold]".to_string(),
+        "#[attr = TrackCaller]" => "#[track_caller]".to_string(),
+        other => other.to_string(),
+    }
+}
@@ -34,6 +34,11 @@ pub enum RustType {
         traits: Vec<String>,
         lifetime: Option<String>,
     },
+    /// `impl Trait` in argument or return position, one entry per bound
+    ImplTrait(Vec<String>),
+    // Non-type generic arguments, e.g. the `'a` and `3` in `Foo<'a, T, 3>`
+    Lifetime(String),
+    Const(String),
     Unit,
     Unknown,
 }
@@ -56,12 +61,25 @@ impl std::fmt::Display for RustType {
                 if *mutable {
                     result.push_str("mut ");
                 }
-                result.push_str(&inner.to_string());
+                // `&dyn A + B` doesn't parse; multi-bound trait objects need parentheses
+                match inner.as_ref() {
+                    RustType::DynTrait { traits, lifetime }
+                        if traits.len() > 1 || lifetime.is_some() =>
+                    {
+                        result.push_str(&format!("({})", inner));
+                    }
+                    RustType::ImplTrait(bounds) if bounds.len() > 1 => {
+                        result.push_str(&format!("({})", inner));
+                    }
+                    _ => result.push_str(&inner.to_string()),
+                }
                 write!(f, "{}", result)
             }
             RustType::Tuple(elements) => {
                 if elements.is_empty() {
                     write!(f, "()")
+                } else if elements.len() == 1 {
+                    write!(f, "({},)", elements[0])
                 } else {
                     let element_strs: Vec<String> =
                         elements.iter().map(|e| e.to_string()).collect();
@@ -88,14 +106,19 @@ impl std::fmt::Display for RustType {
             }
             RustType::QualifiedPath { base, name } => write!(f, "{}::{}", base, name),
             RustType::DynTrait { traits, lifetime } => {
+                // Lifetime bounds follow the traits, and only when rustdoc recorded one:
+                // the elided default (`'static` in `Box<dyn Draw>`) is never spelled out
                 let mut result = "dyn ".to_string();
+                result.push_str(&join_bounds(traits));
                 if let Some(lifetime_str) = lifetime {
+                    result.push_str(" + ");
                     result.push_str(lifetime_str);
-                    result.push(' ');
                 }
-                result.push_str(&traits.join(" + "));
                 write!(f, "{}", result)
             }
+            RustType::ImplTrait(bounds) => write!(f, "impl {}", join_bounds(bounds)),
+            RustType::Lifetime(name) => write!(f, "{}", name),
+            RustType::Const(expr) => write!(f, "{}", expr),
             RustType::Unit => write!(f, "()"),
             RustType::Unknown => write!(f, "..."),
         }
@@ -110,8 +133,17 @@ pub struct GenericParam {
 
 #[derive(Debug, Clone)]
 pub enum GenericParamKind {
-    Type { bounds: Vec<String> },
+    Type {
+        bounds: Vec<String>,
+        /// Default type, as in `T = u32`
+        default: Option<RustType>,
+    },
     Lifetime,
+    /// `const N: usize`, with an optional default expression
+    Const {
+        ty: RustType,
+        default: Option<String>,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -120,6 +152,20 @@ pub struct Generics {
     pub where_clauses: Vec<String>,
 }
 
+/// Join bounds the one way every signature spells them: `Clone + Debug`
+pub fn join_bounds<S: AsRef<str>>(bounds: &[S]) -> String {
+    bounds.iter().map(AsRef::as_ref).collect::<Vec<_>>().join(" + ")
+}
+
+/// `T: Clone + Debug` in generics and where clauses alike, or just `T` when unbounded
+pub fn bounded<S: AsRef<str>>(subject: &str, bounds: &[S]) -> String {
+    if bounds.is_empty() {
+        subject.to_string()
+    } else {
+        format!("{}: {}", subject, join_bounds(bounds))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct FunctionSignature {
     pub name: String,
@@ -128,6 +174,8 @@ pub struct FunctionSignature {
     pub inputs: Vec<(String, RustType)>,
     pub output: RustType,
     pub is_async: bool,
+    /// Calling convention as written after `extern`, absent for the Rust ABI
+    pub abi: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -135,6 +183,17 @@ pub struct ParsedFunction {
     pub signature: FunctionSignature,
     pub docs: Option<String>,
     pub deprecation: Option<Deprecation>,
+    /// Alternate search names from `#[doc(alias = "...")]`
+    pub aliases: Vec<String>,
+    /// Attributes in source form, e.g. `#[inline(always)]`
+    pub attrs: Vec<String>,
+}
+
+impl ParsedFunction {
+    /// Whether the function takes `self` in any form, i.e. is a method
+    pub fn has_receiver(&self) -> bool {
+        self.signature.inputs.first().is_some_and(|(name, _)| name == "self")
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -142,7 +201,6 @@ pub struct ParsedField {
     pub name: String,
     pub visibility: Visibility,
     pub field_type: RustType,
-    #[allow(dead_code)]
     pub docs: Option<String>,
     #[allow(dead_code)]
     pub deprecation: Option<Deprecation>,
@@ -155,9 +213,40 @@ pub struct ParsedStruct {
     pub generics: Generics,
     pub docs: Option<String>,
     pub deprecation: Option<Deprecation>,
+    /// Alternate search names from `#[doc(alias = "...")]`
+    pub aliases: Vec<String>,
     pub fields: Vec<ParsedField>,
     pub methods: Vec<ParsedFunction>,
+    /// Associated types from inherent impls, e.g. `type Output = u8`
+    pub assoc_types: Vec<ParsedAssocType>,
+    /// Associated constants from inherent impls, e.g. `const MAX: usize = 10`
+    pub consts: Vec<ParsedConstant>,
+    /// The inherent impl blocks `methods` and `consts` were gathered from
+    pub inherent_impls: Vec<ParsedInherentImpl>,
     pub trait_impls: Vec<ParsedTraitImpl>,
+    /// Auto traits (`Send`, `Sync`, ...) the type implements
+    pub auto_traits: Vec<String>,
+}
+
+/// An `impl Type { ... }` block with its own generics and where-clause
+#[derive(Debug, Clone)]
+pub struct ParsedInherentImpl {
+    pub generics: Generics,
+    pub for_type: RustType,
+    pub assoc_types: Vec<ParsedAssocType>,
+    pub consts: Vec<ParsedConstant>,
+    pub methods: Vec<ParsedFunction>,
+    /// Docs written on the impl block itself, e.g. `/// Constructors`
+    pub docs: Option<String>,
+}
+
+/// An associated type defined in an inherent impl (`type Output = u8;`)
+#[derive(Debug, Clone)]
+pub struct ParsedAssocType {
+    pub name: String,
+    pub visibility: Visibility,
+    pub ty: RustType,
+    pub docs: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -168,6 +257,10 @@ pub struct ParsedEnum {
     pub variants: Vec<ParsedVariant>,
     pub docs: Option<String>,
     pub deprecation: Option<Deprecation>,
+    /// Alternate search names from `#[doc(alias = "...")]`
+    pub aliases: Vec<String>,
+    /// Auto traits (`Send`, `Sync`, ...) the type implements
+    pub auto_traits: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -175,13 +268,16 @@ pub struct ParsedVariant {
     pub name: String,
     pub kind: VariantKind,
     pub docs: Option<String>,
+    pub deprecation: Option<Deprecation>,
+    /// `#[non_exhaustive]` on the variant itself
+    pub non_exhaustive: bool,
 }
 
 #[derive(Debug, Clone)]
 pub enum VariantKind {
     Unit,
-    Tuple(Vec<RustType>),
-    Struct(Vec<(String, RustType)>),
+    Tuple(Vec<ParsedField>),
+    Struct(Vec<ParsedField>),
 }
 
 #[derive(Debug, Clone)]
@@ -190,15 +286,24 @@ pub struct ParsedTrait {
     pub visibility: Visibility,
     pub generics: Generics,
     pub items: Vec<ParsedTraitItem>,
+    /// Declared as `unsafe trait`
+    pub is_unsafe: bool,
+    /// Has a supertrait that cannot be named outside the defining crate
+    pub is_sealed: bool,
     pub docs: Option<String>,
     pub deprecation: Option<Deprecation>,
+    /// Alternate search names from `#[doc(alias = "...")]`
+    pub aliases: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 pub enum ParsedTraitItem {
     AssocType {
         name: String,
         bounds: Vec<String>,
+        /// Type the trait provides when an impl doesn't name one, as in `type Item = u32;`
+        default: Option<RustType>,
         docs: Option<String>,
     },
     AssocConst {
@@ -212,24 +317,82 @@ pub enum ParsedTraitItem {
 #[derive(Debug, Clone)]
 pub struct ParsedTraitImpl {
     pub trait_path: String,
+    /// Declared as `unsafe impl`
+    pub is_unsafe: bool,
+    /// `#[cfg(...)]` predicates gating the whole impl, e.g. `feature = "advanced"`
+    pub cfg: Vec<String>,
+    /// The impl's own parameters and bounds, e.g. `<T: Clone>` on a derived `Clone`
+    pub generics: Generics,
     pub for_type: RustType,
     pub items: Vec<ParsedTraitImplItem>,
+    /// Default methods from the trait definition that this impl does not override
+    pub inherited: Vec<ParsedFunction>,
     pub docs: Option<String>,
 }
 
+impl ParsedTraitImpl {
+    /// The trait's own name, without its module path
+    pub fn trait_name(&self) -> &str {
+        self.trait_path.rsplit("::").next().unwrap_or(&self.trait_path)
+    }
+}
+
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 pub enum ParsedTraitImplItem {
     AssocType { name: String, ty: RustType },
     Method(ParsedFunction),
 }
 
+impl ParsedTraitImplItem {
+    /// Deterministic ordering key: associated types first, then methods, each by name
+    pub fn sort_key(&self) -> (u8, &str) {
+        match self {
+            ParsedTraitImplItem::AssocType { name, .. } => (0, name),
+            ParsedTraitImplItem::Method(func) => (1, &func.signature.name),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ParsedConstant {
     pub name: String,
     pub visibility: Visibility,
     pub ty: RustType,
+    /// Initializer expression, e.g. `1000`
+    pub value: Option<String>,
+    pub docs: Option<String>,
+    pub deprecation: Option<Deprecation>,
+    /// Alternate search names from `#[doc(alias = "...")]`
+    pub aliases: Vec<String>,
+}
+
+/// `type Name<T> = Target<T>;`
+#[derive(Debug, Clone)]
+pub struct ParsedTypeAlias {
+    pub name: String,
+    pub visibility: Visibility,
+    pub generics: Generics,
+    pub ty: RustType,
     pub docs: Option<String>,
     pub deprecation: Option<Deprecation>,
+    /// Alternate search names from `#[doc(alias = "...")]`
+    pub aliases: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ParsedStatic {
+    pub name: String,
+    pub visibility: Visibility,
+    pub ty: RustType,
+    /// `static mut`
+    pub mutable: bool,
+    /// Initializer expression, e.g. `42`
+    pub value: Option<String>,
+    pub docs: Option<String>,
+    pub deprecation: Option<Deprecation>,
+    /// Alternate search names from `#[doc(alias = "...")]`
+    pub aliases: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -238,6 +401,26 @@ pub struct ParsedModule {
     pub visibility: Visibility,
     pub items: Vec<ParsedItem>,
     pub docs: Option<String>,
+    /// Attributes applied to the module itself, e.g. `#[deny(missing_docs)]`
+    pub attrs: Vec<String>,
+}
+
+/// An item inlined where a `pub use` re-exports it
+#[derive(Debug, Clone)]
+pub struct ParsedReExport {
+    /// Where the item is defined, e.g. `complex::math::Point`
+    pub source: String,
+    /// Docs written on the `pub use` itself, which belong to this re-export alone
+    pub docs: Option<String>,
+    pub item: Box<ParsedItem>,
+}
+
+/// An item found in only some of the targets merged by `--target all`
+#[derive(Debug, Clone)]
+pub struct ParsedTargeted {
+    /// Target triples whose docs contain the item, e.g. `x86_64-pc-windows-msvc`
+    pub targets: Vec<String>,
+    pub item: Box<ParsedItem>,
 }
 
 #[derive(Debug, Clone)]
@@ -246,6 +429,21 @@ pub struct ParsedMacro {
     pub docs: Option<String>,
 }
 
+/// A type doccer has no rendering for; it shows up as `...` in the output
+#[derive(Debug, Clone)]
+pub struct UnknownType {
+    /// The innermost named item the type appears in
+    pub item: String,
+    /// The type's rustdoc JSON, as it failed to parse
+    pub json: String,
+}
+
+impl std::fmt::Display for UnknownType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "in `{}`: {}", self.item, self.json)
+    }
+}
+
 
 #[derive(Debug, Clone)]
 pub enum ParsedItem {
@@ -254,7 +452,11 @@ pub enum ParsedItem {
     Enum(ParsedEnum),
     Trait(ParsedTrait),
     Constant(ParsedConstant),
+    Static(ParsedStatic),
+    TypeAlias(ParsedTypeAlias),
     Module(ParsedModule),
     Macro(ParsedMacro),
     TraitImpl(ParsedTraitImpl),
+    ReExport(ParsedReExport),
+    Targeted(ParsedTargeted),
 }
\ No newline at end of file
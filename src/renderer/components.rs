@@ -1,6 +1,11 @@
 use crate::parser::*;
+use crate::renderer::traits::{Render, RenderContext};
 use rustdoc_types::Visibility;
 
+/// Default column limit: signatures whose inline where-clause would exceed it
+/// render one predicate per line instead
+pub const WHERE_CLAUSE_WRAP_WIDTH: usize = 100;
+
 /// Helper for rendering type signatures
 pub struct TypeRenderer;
 
@@ -13,7 +18,11 @@ impl TypeRenderer {
         match vis {
             Visibility::Public => "pub ".to_string(),
             Visibility::Crate => "pub(crate) ".to_string(),
-            Visibility::Restricted { path, .. } => format!("pub({}) ", path),
+            // rustdoc spells module paths from the crate root as `::a::b`
+            Visibility::Restricted { path, .. } => match path.strip_prefix("::") {
+                Some(module) => format!("pub(in crate::{}) ", module),
+                None => format!("pub({}) ", path),
+            },
             Visibility::Default => String::new(),
         }
     }
@@ -23,16 +32,20 @@ impl TypeRenderer {
             return String::new();
         }
 
-        let param_strs: Vec<String> = generics
-            .params
+        // Lifetimes must lead; types and consts may interleave, and their order is
+        // what positional arguments bind to, so it is kept as declared
+        let mut params: Vec<&GenericParam> = generics.params.iter().collect();
+        params.sort_by_key(|p| !matches!(p.kind, GenericParamKind::Lifetime));
+
+        let param_strs: Vec<String> = params
             .iter()
             .map(|p| match &p.kind {
-                GenericParamKind::Type { bounds } => {
-                    if bounds.is_empty() {
-                        p.name.clone()
-                    } else {
-                        format!("{}: {}", p.name, bounds.join(" + "))
+                GenericParamKind::Type { bounds, default } => {
+                    let mut param = bounded(&p.name, bounds);
+                    if let Some(default) = default {
+                        param.push_str(&format!(" = {}", self.render_type(default)));
                     }
+                    param
                 }
                 GenericParamKind::Lifetime => {
                     if p.name.starts_with('\'') {
@@ -41,6 +54,13 @@ impl TypeRenderer {
                         format!("'{}", p.name)
                     }
                 }
+                GenericParamKind::Const { ty, default } => {
+                    let mut param = format!("const {}: {}", p.name, self.render_type(ty));
+                    if let Some(default) = default {
+                        param.push_str(&format!(" = {}", default));
+                    }
+                    param
+                }
             })
             .collect();
 
@@ -54,6 +74,63 @@ impl TypeRenderer {
             format!(" where {}", generics.where_clauses.join(", "))
         }
     }
+
+    /// Render the where clause inline, or break it onto indented lines when the
+    /// full signature line would exceed `width` columns
+    pub fn render_where_clause_wrapped(
+        &self,
+        generics: &Generics,
+        signature: &str,
+        indent: &str,
+        width: usize,
+    ) -> String {
+        let inline = self.render_where_clause(generics);
+        if inline.is_empty() || indent.len() + signature.len() + inline.len() <= width {
+            return inline;
+        }
+
+        let mut output = format!("\n{}where", indent);
+        for clause in &generics.where_clauses {
+            output.push_str(&format!("\n{}    {},", indent, clause));
+        }
+        output
+    }
+}
+
+/// The title of a Markdown heading line such as `# Examples`
+fn doc_heading(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start();
+    let hashes = trimmed.chars().take_while(|c| *c == '#').count();
+    if (1..=3).contains(&hashes) && trimmed[hashes..].starts_with(' ') {
+        Some(trimmed[hashes..].trim())
+    } else {
+        None
+    }
+}
+
+/// Keep the summary before the first heading plus the sections named in `keep`
+/// (matched case-insensitively)
+pub fn filter_doc_sections(docs: &str, keep: &[String]) -> String {
+    let mut kept = Vec::new();
+    let mut keeping = true;
+    let mut in_code_block = false;
+    for line in docs.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+        } else if !in_code_block {
+            if let Some(heading) = doc_heading(line) {
+                keeping = keep.iter().any(|k| k.eq_ignore_ascii_case(heading));
+            }
+        }
+        if keeping {
+            kept.push(line);
+        }
+    }
+
+    while kept.last().is_some_and(|line| line.trim().is_empty()) {
+        kept.pop();
+    }
+    kept.join("\n")
 }
 
 /// Helper for rendering documentation comments
@@ -61,31 +138,201 @@ pub struct DocRenderer;
 
 impl DocRenderer {
     pub fn render_docs(&self, docs: Option<&String>, indent: &str) -> String {
+        self.render_prefixed(docs, indent, "///")
+    }
+
+    /// Render crate and module docs with the inner `//!` prefix
+    pub fn render_inner_docs(&self, docs: Option<&String>, indent: &str) -> String {
+        self.render_prefixed(docs, indent, "//!")
+    }
+
+    /// Render item docs, keeping only the `--sections` the context asks for
+    pub fn render_item_docs(
+        &self,
+        docs: Option<&String>,
+        indent: &str,
+        context: &RenderContext,
+    ) -> String {
+        if context.signatures_only {
+            return String::new();
+        }
+        match (docs, &context.sections) {
+            (Some(docs), Some(keep)) => {
+                let filtered = filter_doc_sections(docs, keep);
+                if filtered.is_empty() {
+                    String::new()
+                } else {
+                    self.render_docs(Some(&filtered), indent)
+                }
+            }
+            _ => self.render_docs(docs, indent),
+        }
+    }
+
+    fn render_prefixed(&self, docs: Option<&String>, indent: &str, prefix: &str) -> String {
         let Some(docs) = docs else {
             return String::new();
         };
 
         let mut output = String::new();
+        let mut previous_blank = true;
+        let mut in_code_block = false;
         for line in docs.lines() {
+            if line.trim_start().starts_with("```") {
+                in_code_block = !in_code_block;
+            }
+
+            // Keep section headings visually apart from the paragraph above them
+            if !in_code_block && doc_heading(line).is_some() && !previous_blank {
+                output.push_str(&format!("{}{}\n", indent, prefix));
+            }
+
             if line.trim().is_empty() {
-                output.push_str(&format!("{}///\n", indent));
+                output.push_str(&format!("{}{}\n", indent, prefix));
             } else {
-                output.push_str(&format!("{}/// {}\n", indent, line));
+                output.push_str(&format!("{}{} {}\n", indent, prefix, line));
             }
+            previous_blank = line.trim().is_empty();
         }
         output
     }
 
-    pub fn render_deprecation(&self, deprecation: Option<&rustdoc_types::Deprecation>, indent: &str) -> String {
+    /// Render the deprecation notice, preceded by a `CHANGED since` marker when the
+    /// deprecation happened after the `--since` baseline
+    pub fn render_deprecation(
+        &self,
+        deprecation: Option<&rustdoc_types::Deprecation>,
+        indent: &str,
+        context: &RenderContext,
+    ) -> String {
         let Some(deprecation) = deprecation else {
             return String::new();
         };
 
-        if let Some(since) = &deprecation.since {
-            format!("{}DEPRECATED since {}\n", indent, since)
+        let Some(since) = &deprecation.since else {
+            return format!("{}DEPRECATED\n", indent);
+        };
+        let mut output = String::new();
+        if let Some(baseline) = &context.since {
+            if is_newer_version(since, baseline) {
+                output.push_str(&format!(
+                    "{}CHANGED since {} (deprecated in {})\n",
+                    indent, baseline, since
+                ));
+            }
+        }
+        output.push_str(&format!("{}DEPRECATED since {}\n", indent, since));
+        output
+    }
+
+    pub fn render_auto_traits(&self, auto_traits: &[String], indent: &str) -> String {
+        if auto_traits.is_empty() {
+            format!("{}// auto: none\n", indent)
         } else {
-            format!("{}DEPRECATED\n", indent)
+            format!("{}// auto: {}\n", indent, auto_traits.join(", "))
+        }
+    }
+
+    /// Render the `#[doc(alias)]` names an item can also be found under
+    pub fn render_aliases(&self, aliases: &[String], indent: &str, context: &RenderContext) -> String {
+        if aliases.is_empty() || context.no_auto_comment {
+            return String::new();
+        }
+        format!("{}// aliases: {}\n", indent, aliases.join(", "))
+    }
+}
+
+/// Whether dotted version `version` is later than `baseline`, e.g. `1.10.0` > `1.9`.
+/// Versions that aren't purely numeric never compare as newer.
+pub fn is_newer_version(version: &str, baseline: &str) -> bool {
+    let parse = |v: &str| -> Option<Vec<u64>> {
+        v.trim_start_matches('v').split('.').map(|part| part.parse().ok()).collect()
+    };
+    let (Some(mut version), Some(mut baseline)) = (parse(version), parse(baseline)) else {
+        return false;
+    };
+    let len = version.len().max(baseline.len());
+    version.resize(len, 0);
+    baseline.resize(len, 0);
+    version > baseline
+}
+
+/// Section titles for `--group-by-kind`, in the order the groups are rendered
+const KIND_GROUPS: [&str; 10] = [
+    "Macros",
+    "Modules",
+    "Structs",
+    "Enums",
+    "Traits",
+    "Functions",
+    "Type Aliases",
+    "Constants",
+    "Statics",
+    "Implementations",
+];
+
+fn kind_group(item: &ParsedItem) -> &'static str {
+    match item {
+        ParsedItem::Macro(_) => KIND_GROUPS[0],
+        ParsedItem::Module(_) => KIND_GROUPS[1],
+        ParsedItem::Struct(_) => KIND_GROUPS[2],
+        ParsedItem::Enum(_) => KIND_GROUPS[3],
+        ParsedItem::Trait(_) => KIND_GROUPS[4],
+        ParsedItem::Function(_) => KIND_GROUPS[5],
+        ParsedItem::TypeAlias(_) => KIND_GROUPS[6],
+        ParsedItem::Constant(_) => KIND_GROUPS[7],
+        ParsedItem::Static(_) => KIND_GROUPS[8],
+        ParsedItem::TraitImpl(_) => KIND_GROUPS[9],
+        ParsedItem::ReExport(r) => kind_group(&r.item),
+        ParsedItem::Targeted(t) => kind_group(&t.item),
+    }
+}
+
+/// Render a module's items under `# Structs`, `# Traits`, ... headers, keeping
+/// source order within each group
+pub fn render_grouped_by_kind(items: &[&ParsedItem], context: &RenderContext) -> String {
+    let indent = context.indent();
+    let mut output = String::new();
+    for title in KIND_GROUPS {
+        let group: Vec<&&ParsedItem> = items.iter().filter(|item| kind_group(item) == title).collect();
+        if group.is_empty() {
+            continue;
+        }
+        output.push_str(&format!("{}# {}\n\n", indent, title));
+        for item in group {
+            output.push_str(&item.render(context));
         }
     }
+    output
 }
 
+/// Order a module's items for rendering: source order by default, or every
+/// non-module item before the first submodule under `--breadth-first`
+pub fn order_module_items<'a>(items: &[&'a ParsedItem], context: &RenderContext) -> Vec<&'a ParsedItem> {
+    if !context.breadth_first {
+        return items.to_vec();
+    }
+    let (modules, others): (Vec<&ParsedItem>, Vec<&ParsedItem>) =
+        items.iter().partition(|item| kind_group(item) == KIND_GROUPS[1]);
+    others.into_iter().chain(modules).collect()
+}
+
+/// Helper for rendering item attributes
+pub struct AttrRenderer;
+
+impl AttrRenderer {
+    /// Render codegen hints such as `#[inline]` and `#[cold]`, and `#[track_caller]`,
+    /// which moves panic locations to the caller, one per line
+    pub fn render_perf_attrs(&self, attrs: &[String], indent: &str) -> String {
+        attrs
+            .iter()
+            .filter(|attr| {
+                attr.starts_with("#[inline")
+                    || attr.starts_with("#[cold")
+                    || attr.starts_with("#[target_feature")
+                    || attr.as_str() == "#[track_caller]"
+            })
+            .map(|attr| format!("{}{}\n", indent, attr))
+            .collect()
+    }
+}
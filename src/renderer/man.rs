@@ -0,0 +1,301 @@
+use crate::parser::*;
+use crate::renderer::components::*;
+use crate::renderer::traits::*;
+
+/// Renders a parsed crate as a roff man page (section 3)
+pub struct ManRenderer;
+
+/// A single `.TP` entry: the signature line and its documentation
+struct ManEntry {
+    signature: String,
+    docs: Option<String>,
+}
+
+/// Entries grouped by item kind, in the order their `.SH` sections are emitted
+#[derive(Default)]
+struct ManSections {
+    macros: Vec<ManEntry>,
+    structs: Vec<ManEntry>,
+    enums: Vec<ManEntry>,
+    traits: Vec<ManEntry>,
+    functions: Vec<ManEntry>,
+    type_aliases: Vec<ManEntry>,
+    constants: Vec<ManEntry>,
+    statics: Vec<ManEntry>,
+}
+
+impl ManRenderer {
+    pub fn render(
+        &self,
+        module: &ParsedModule,
+        crate_version: Option<&str>,
+        context: &RenderContext,
+    ) -> String {
+        let mut output = String::new();
+
+        output.push_str(&format!(
+            ".TH \"{}\" 3 \"\" \"{}\" \"Rust API\"\n",
+            escape_roff(&module.name),
+            escape_roff(crate_version.unwrap_or(""))
+        ));
+
+        output.push_str(".SH NAME\n");
+        let summary = module
+            .docs
+            .as_deref()
+            .and_then(|docs| docs.lines().find(|line| !line.trim().is_empty()));
+        match summary {
+            Some(summary) => output.push_str(&format!(
+                "{} \\- {}\n",
+                escape_roff(&module.name),
+                escape_roff(summary.trim())
+            )),
+            None => output.push_str(&format!("{}\n", escape_roff(&module.name))),
+        }
+
+        if let Some(docs) = shown_docs(module.docs.as_ref(), context) {
+            output.push_str(".SH DESCRIPTION\n");
+            output.push_str(&render_paragraphs(&docs, ".PP"));
+        }
+
+        let mut sections = ManSections::default();
+        collect_entries(&module.items, "", context, &mut sections);
+
+        let ordered = [
+            ("MACROS", &sections.macros),
+            ("STRUCTS", &sections.structs),
+            ("ENUMS", &sections.enums),
+            ("TRAITS", &sections.traits),
+            ("FUNCTIONS", &sections.functions),
+            ("TYPE ALIASES", &sections.type_aliases),
+            ("CONSTANTS", &sections.constants),
+            ("STATICS", &sections.statics),
+        ];
+
+        for (title, entries) in ordered {
+            if entries.is_empty() {
+                continue;
+            }
+            output.push_str(&format!(".SH {}\n", title));
+            for entry in entries {
+                output.push_str(".TP\n");
+                let mut lines = entry.signature.lines();
+                if let Some(first) = lines.next() {
+                    output.push_str(&format!(".B \"{}\"\n", escape_roff(first.trim())));
+                }
+                // Wrapped where-clauses continue on their own lines; left to fill
+                // mode, roff would join them into the entry's body text
+                let continuation: Vec<&str> = lines.collect();
+                if !continuation.is_empty() {
+                    output.push_str(".nf\n");
+                    for line in continuation {
+                        output.push_str(&format!("{}\n", escape_roff_line(line.trim_end())));
+                    }
+                    output.push_str(".fi\n");
+                }
+                if let Some(docs) = shown_docs(entry.docs.as_ref(), context) {
+                    output.push_str(&render_paragraphs(&docs, ".IP"));
+                }
+            }
+        }
+
+        output
+    }
+}
+
+/// Walk the module tree, qualifying nested item names with their module path
+fn collect_entries(
+    items: &[ParsedItem],
+    prefix: &str,
+    context: &RenderContext,
+    sections: &mut ManSections,
+) {
+    let type_renderer = TypeRenderer;
+
+    for item in items {
+        match item {
+            ParsedItem::Macro(m) => sections.macros.push(ManEntry {
+                signature: m.signature.clone(),
+                docs: m.docs.clone(),
+            }),
+            ParsedItem::Struct(s) => sections.structs.push(ManEntry {
+                signature: with_where_clause(
+                    format!(
+                        "{}struct {}{}{}",
+                        type_renderer.render_visibility(&s.visibility),
+                        prefix,
+                        s.name,
+                        type_renderer.render_generics(&s.generics)
+                    ),
+                    &s.generics,
+                    context,
+                ),
+                docs: s.docs.clone(),
+            }),
+            ParsedItem::Enum(e) => sections.enums.push(ManEntry {
+                signature: with_where_clause(
+                    format!(
+                        "{}enum {}{}{}",
+                        type_renderer.render_visibility(&e.visibility),
+                        prefix,
+                        e.name,
+                        type_renderer.render_generics(&e.generics)
+                    ),
+                    &e.generics,
+                    context,
+                ),
+                docs: e.docs.clone(),
+            }),
+            ParsedItem::Trait(t) => sections.traits.push(ManEntry {
+                signature: with_where_clause(
+                    format!(
+                        "{}{}trait {}{}{}",
+                        type_renderer.render_visibility(&t.visibility),
+                        if t.is_unsafe { "unsafe " } else { "" },
+                        prefix,
+                        t.name,
+                        type_renderer.render_generics(&t.generics)
+                    ),
+                    &t.generics,
+                    context,
+                ),
+                docs: t.docs.clone(),
+            }),
+            ParsedItem::Function(f) => {
+                // Reuse the text renderer for the signature itself so both
+                // backends agree on ABI, generics and where-clause layout
+                let mut bare = f.clone();
+                bare.docs = None;
+                bare.deprecation = None;
+                bare.aliases.clear();
+                bare.attrs.clear();
+                bare.signature.name = format!("{}{}", prefix, f.signature.name);
+                sections.functions.push(ManEntry {
+                    signature: bare.render(&context.with_depth(0)),
+                    docs: f.docs.clone(),
+                });
+            }
+            ParsedItem::Constant(c) => sections.constants.push(ManEntry {
+                signature: format!(
+                    "{}const {}{}: {}{}",
+                    type_renderer.render_visibility(&c.visibility),
+                    prefix,
+                    c.name,
+                    type_renderer.render_type(&c.ty),
+                    c.value.as_ref().map(|v| format!(" = {}", v)).unwrap_or_default()
+                ),
+                docs: c.docs.clone(),
+            }),
+            ParsedItem::TypeAlias(t) => sections.type_aliases.push(ManEntry {
+                signature: format!(
+                    "{}type {}{}{}{} = {}",
+                    type_renderer.render_visibility(&t.visibility),
+                    prefix,
+                    t.name,
+                    type_renderer.render_generics(&t.generics),
+                    type_renderer.render_where_clause(&t.generics),
+                    type_renderer.render_type(&t.ty)
+                ),
+                docs: t.docs.clone(),
+            }),
+            ParsedItem::Static(s) => sections.statics.push(ManEntry {
+                signature: format!(
+                    "{}static {}{}{}: {}{}",
+                    type_renderer.render_visibility(&s.visibility),
+                    if s.mutable { "mut " } else { "" },
+                    prefix,
+                    s.name,
+                    type_renderer.render_type(&s.ty),
+                    s.value.as_ref().map(|v| format!(" = {}", v)).unwrap_or_default()
+                ),
+                docs: s.docs.clone(),
+            }),
+            ParsedItem::Module(m) => {
+                let nested = format!("{}{}::", prefix, m.name);
+                collect_entries(&m.items, &nested, context, sections);
+            }
+            ParsedItem::ReExport(r) => {
+                collect_entries(std::slice::from_ref(r.item.as_ref()), prefix, context, sections)
+            }
+            ParsedItem::Targeted(t) => {
+                collect_entries(std::slice::from_ref(t.item.as_ref()), prefix, context, sections)
+            }
+            // Impl blocks have no standalone name to list
+            ParsedItem::TraitImpl(_) => {}
+        }
+    }
+}
+
+/// Append the where clause the text renderer would give `signature`, wrapped the
+/// same way functions are
+fn with_where_clause(signature: String, generics: &Generics, context: &RenderContext) -> String {
+    let where_clause = TypeRenderer.render_where_clause_wrapped(generics, &signature, "", context.width);
+    signature + &where_clause
+}
+
+/// The part of `docs` to print: nothing under `--emit-signatures-only`, otherwise
+/// the `--sections` the context asks for, as in text output
+fn shown_docs(docs: Option<&String>, context: &RenderContext) -> Option<String> {
+    if context.signatures_only {
+        return None;
+    }
+    let docs = docs?;
+    let shown = match &context.sections {
+        Some(keep) => filter_doc_sections(docs, keep),
+        None => docs.clone(),
+    };
+    (!shown.is_empty()).then_some(shown)
+}
+
+/// Emit doc text as roff paragraphs, inserting `paragraph_macro` at blank lines
+fn render_paragraphs(docs: &str, paragraph_macro: &str) -> String {
+    let mut output = String::new();
+    let mut in_paragraph = false;
+    let mut in_table = false;
+
+    for line in docs.lines() {
+        // Markdown tables only line up if roff leaves their rows unfilled
+        let is_table_row = line.trim_start().starts_with('|');
+        if is_table_row != in_table {
+            output.push_str(if is_table_row { ".nf\n" } else { ".fi\n" });
+            in_table = is_table_row;
+        }
+
+        if line.trim().is_empty() {
+            if in_paragraph {
+                output.push_str(&format!("{}\n", paragraph_macro));
+                in_paragraph = false;
+            }
+            continue;
+        }
+        output.push_str(&format!("{}\n", escape_roff_line(line)));
+        in_paragraph = true;
+    }
+    if in_table {
+        output.push_str(".fi\n");
+    }
+
+    // Drop a trailing paragraph break left by blank lines at the end
+    let trailing = format!("{}\n", paragraph_macro);
+    if output.ends_with(&trailing) {
+        output.truncate(output.len() - trailing.len());
+    }
+    output
+}
+
+/// Escape text that appears inside a roff request argument
+fn escape_roff(text: &str) -> String {
+    text.replace('\\', "\\e")
+        .replace('-', "\\-")
+        .replace('"', "\\(dq")
+}
+
+/// Escape a full text line, guarding against lines that roff would read as requests
+fn escape_roff_line(line: &str) -> String {
+    let escaped = line.replace('\\', "\\e").replace('-', "\\-");
+    if escaped.starts_with('.') || escaped.starts_with('\'') {
+        format!("\\&{}", escaped)
+    } else {
+        escaped
+    }
+}
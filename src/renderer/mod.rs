@@ -1,7 +1,12 @@
+#[allow(clippy::module_inception)]
 pub mod renderer;
 pub mod traits;
 pub mod components;
 pub mod renders;
+pub mod man;
+pub mod stats;
 
 pub use renderer::*;
 pub use traits::*;
+pub use man::ManRenderer;
+pub use stats::CrateStats;
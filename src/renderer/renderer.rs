@@ -1,21 +1,60 @@
 use crate::parser::*;
+use crate::renderer::components::{order_module_items, render_grouped_by_kind, DocRenderer, TypeRenderer};
 use crate::renderer::traits::*;
 
 pub struct ParsedRenderer;
 
 impl ParsedRenderer {
-    pub fn render(&self, module: &ParsedModule, crate_version: Option<&str>) -> String {
+    /// Render the crate using the options carried by `context`
+    pub fn render(
+        &self,
+        module: &ParsedModule,
+        crate_version: Option<&str>,
+        context: &RenderContext,
+    ) -> String {
         let mut output = String::new();
 
         // Render crate header
-        output.push_str(&format!("# Crate: {}\n\n", module.name));
+        if !context.no_crate_header {
+            output.push_str(&format!("# Crate: {}\n\n", module.name));
+        }
 
+        // Attributes on the root module apply to the whole crate, i.e. `#![...]`
+        let crate_attrs: Vec<String> = module
+            .attrs
+            .iter()
+            .filter(|attr| !attr.starts_with("#[attr ="))
+            .map(|attr| match attr.strip_prefix("#[") {
+                Some(rest) => format!("#![{}", rest),
+                None => attr.clone(),
+            })
+            .collect();
+        if !crate_attrs.is_empty() && !context.no_crate_header {
+            output.push_str(&format!("{}\n\n", crate_attrs.join("\n")));
+        }
+
+        let mut header = Vec::new();
         if let Some(version) = crate_version {
-            output.push_str(&format!("Version: {}\n\n", version));
+            header.push(format!("Version: {}", version));
+        }
+        if let Some(metadata) = &context.metadata {
+            if let Some(license) = &metadata.license {
+                header.push(format!("License: {}", license));
+            }
+            if let Some(repository) = &metadata.repository {
+                header.push(format!("Repository: {}", repository));
+            }
+            if !metadata.authors.is_empty() {
+                header.push(format!("Authors: {}", metadata.authors.join(", ")));
+            }
+        }
+        if !header.is_empty() && !context.no_crate_header {
+            output.push_str(&format!("{}\n\n", header.join("\n")));
         }
 
-        if let Some(docs) = &module.docs {
-            output.push_str(&format!("{}\n\n", docs));
+        if module.docs.is_some() && !context.signatures_only {
+            output.push_str(&DocRenderer.render_inner_docs(module.docs.as_ref(), ""));
+            output.push('\n');
         }
 
         // Extract macros first to render them at the top (for compatibility with expected output)
@@ -24,19 +63,91 @@ impl ParsedRenderer {
             .iter()
             .partition(|item| matches!(item, ParsedItem::Macro(_)));
 
-        let context = RenderContext::new().with_depth(1);
+        let context = context.with_depth(1);
 
-        // First, render all macros
-        for item in &macros {
-            output.push_str(&item.render(&context));
+        if context.group_by_kind {
+            let mut items: Vec<&ParsedItem> = module.items.iter().collect();
+            let omitted = truncate_items(&mut items, context.max_items);
+            output.push_str(&render_grouped_by_kind(&items, &context));
+            output.push_str(&render_omitted_note(omitted, &context));
+            return output;
         }
 
-        // Then render all other items
-        for item in &other_items {
+        // Macros render first, then all other items
+        let mut items = macros;
+        items.extend(order_module_items(&other_items, &context));
+        let omitted = truncate_items(&mut items, context.max_items);
+        for item in items {
             output.push_str(&item.render(&context));
         }
+        output.push_str(&render_omitted_note(omitted, &context));
 
 
         output
     }
-}
\ No newline at end of file
+
+    /// Split the crate into `(file name, contents)` pages for `--output-dir`: an
+    /// `index.txt` with the crate header and root items, then one page per
+    /// top-level module, which the index lists by file name
+    pub fn render_split(
+        &self,
+        module: &ParsedModule,
+        crate_version: Option<&str>,
+        context: &RenderContext,
+    ) -> Vec<(String, String)> {
+        let (modules, root_items): (Vec<&ParsedItem>, Vec<&ParsedItem>) = module
+            .items
+            .iter()
+            .partition(|item| matches!(item, ParsedItem::Module(_)));
+        let root = ParsedModule {
+            items: root_items.into_iter().cloned().collect(),
+            ..module.clone()
+        };
+
+        let mut index = self.render(&root, crate_version, context);
+        let mut pages = Vec::new();
+        let indent = context.with_depth(1).indent();
+        for item in modules {
+            let ParsedItem::Module(submodule) = item else {
+                continue;
+            };
+            let file_name = format!("{}.txt", submodule.name);
+            index.push_str(&format!(
+                "{}{}mod {} // {}\n",
+                indent,
+                TypeRenderer.render_visibility(&submodule.visibility),
+                submodule.name,
+                file_name
+            ));
+            pages.push((file_name, submodule.render(&context.with_depth(0))));
+        }
+
+        pages.insert(0, ("index.txt".to_string(), index));
+        pages
+    }
+}
+
+/// Keep at most `max` top-level items, returning how many were dropped
+fn truncate_items(items: &mut Vec<&ParsedItem>, max: Option<usize>) -> usize {
+    match max {
+        Some(max) if items.len() > max => {
+            let omitted = items.len() - max;
+            items.truncate(max);
+            omitted
+        }
+        _ => 0,
+    }
+}
+
+fn render_omitted_note(omitted: usize, context: &RenderContext) -> String {
+    if omitted == 0 || context.no_auto_comment {
+        return String::new();
+    }
+    let noun = if omitted == 1 { "item" } else { "items" };
+    format!(
+        "{}// ... {} more {} (pass an item path or use --exclude to narrow)\n",
+        context.indent(),
+        omitted,
+        noun
+    )
+}
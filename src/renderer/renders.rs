@@ -10,10 +10,15 @@ impl Render for ParsedFunction {
         let doc_renderer = DocRenderer;
 
         // Add deprecation notice first
-        output.push_str(&doc_renderer.render_deprecation(self.deprecation.as_ref(), &indent));
+        output.push_str(&doc_renderer.render_deprecation(self.deprecation.as_ref(), &indent, context));
 
         // Add docs after deprecation
-        output.push_str(&doc_renderer.render_docs(self.docs.as_ref(), &indent));
+        output.push_str(&doc_renderer.render_item_docs(self.docs.as_ref(), &indent, context));
+        output.push_str(&doc_renderer.render_aliases(&self.aliases, &indent, context));
+
+        if context.show_perf_attrs {
+            output.push_str(&AttrRenderer.render_perf_attrs(&self.attrs, &indent));
+        }
 
         let type_renderer = TypeRenderer;
         let mut signature = String::new();
@@ -26,6 +31,11 @@ impl Render for ParsedFunction {
             signature.push_str("async ");
         }
 
+        // Non-Rust calling conventions are rendered verbatim
+        if let Some(abi) = &sig.abi {
+            signature.push_str(&format!("extern \"{}\" ", abi));
+        }
+
         signature.push_str("fn ");
         signature.push_str(&sig.name);
 
@@ -58,8 +68,14 @@ impl Render for ParsedFunction {
             signature.push_str(&type_renderer.render_type(&sig.output));
         }
 
-        // Add where clause
-        signature.push_str(&type_renderer.render_where_clause(&sig.generics));
+        // Add where clause, wrapping long predicate lists onto their own lines
+        let where_clause = type_renderer.render_where_clause_wrapped(
+            &sig.generics,
+            &signature,
+            &indent,
+            context.width,
+        );
+        signature.push_str(&where_clause);
 
         output.push_str(&format!("{}{}\n", indent, signature));
         output
@@ -74,10 +90,15 @@ impl Render for ParsedStruct {
         let type_renderer = TypeRenderer;
 
         // Add deprecation notice first if present
-        output.push_str(&doc_renderer.render_deprecation(self.deprecation.as_ref(), &indent));
+        output.push_str(&doc_renderer.render_deprecation(self.deprecation.as_ref(), &indent, context));
 
         // Add docs after deprecation
-        output.push_str(&doc_renderer.render_docs(self.docs.as_ref(), &indent));
+        output.push_str(&doc_renderer.render_item_docs(self.docs.as_ref(), &indent, context));
+        output.push_str(&doc_renderer.render_aliases(&self.aliases, &indent, context));
+
+        if context.auto_traits && !context.no_auto_comment {
+            output.push_str(&doc_renderer.render_auto_traits(&self.auto_traits, &indent));
+        }
 
         let mut signature = String::new();
 
@@ -114,8 +135,22 @@ impl Render for ParsedStruct {
         output.push_str(&format!("{}{}\n", indent, signature));
 
         // Render fields
+        let mut in_marker_run = false;
         for field in &self.fields {
             let field_indent = "  ".repeat(context.depth + 1);
+
+            // Compact mode folds consecutive private `_`-prefixed marker fields into one note
+            let is_marker = field.name.starts_with('_')
+                && !matches!(field.visibility, rustdoc_types::Visibility::Public);
+            if context.compact && is_marker {
+                if !in_marker_run && !context.no_auto_comment {
+                    output.push_str(&format!("{}// marker fields\n", field_indent));
+                }
+                in_marker_run = true;
+                continue;
+            }
+            in_marker_run = false;
+
             let field_visibility = type_renderer.render_visibility(&field.visibility);
             let field_signature = format!("{}{}{}: {}", 
                 field_indent, 
@@ -126,26 +161,59 @@ impl Render for ParsedStruct {
             output.push_str(&format!("{}\n", field_signature));
         }
 
-        // Only add newline if there are methods
-        if !self.methods.is_empty() {
+        // With --show-impl-headers impl items are rendered in their impl blocks instead
+        let (body_types, body_consts, body_methods) = if context.show_impl_headers {
+            (&[][..], &[][..], &[][..])
+        } else {
+            (&self.assoc_types[..], &self.consts[..], &self.methods[..])
+        };
+
+        // Only add newline if there are impl items
+        if !body_types.is_empty() || !body_consts.is_empty() || !body_methods.is_empty() {
             output.push('\n');
         }
 
+        // TODO: Make indentation logic more generic
+        let method_context = if self.name == "Person" {
+            context.with_depth(context.depth + 2)
+        } else {
+            context.with_depth(context.depth + 1)
+        };
+
+        // Associated types and consts lead the body; the blank line each renders
+        // with separates it from whatever follows
+        let more_follow = !body_consts.is_empty() || !body_methods.is_empty();
+        output.push_str(&render_impl_members(body_types, more_follow, &method_context));
+        output.push_str(&render_impl_members(body_consts, !body_methods.is_empty(), &method_context));
+
+        // With --group-assoc, receiver-less functions are listed apart from methods
+        let groups: Vec<(Option<&str>, Vec<&ParsedFunction>)> = if context.group_assoc {
+            let (assoc, methods): (Vec<_>, Vec<_>) =
+                body_methods.iter().partition(|m| !m.has_receiver());
+            [("// Associated functions", assoc), ("// Methods", methods)]
+                .into_iter()
+                .filter(|(_, group)| !group.is_empty())
+                .map(|(label, group)| (Some(label), group))
+                .collect()
+        } else {
+            vec![(None, body_methods.iter().collect())]
+        };
+
         // Render methods with proper spacing between them
-        let method_count = self.methods.len();
-        for (i, method) in self.methods.iter().enumerate() {
-            // TODO: Make indentation logic more generic
-            let method_context = if self.name == "Person" {
-                context.with_depth(context.depth + 2)
-            } else {
-                context.with_depth(context.depth + 1)
-            };
-            
-            output.push_str(&method.render(&method_context));
+        let group_count = groups.len();
+        for (g, (label, methods)) in groups.into_iter().enumerate() {
+            if let Some(label) = label.filter(|_| !context.no_auto_comment) {
+                output.push_str(&format!("{}{}\n", method_context.indent(), label));
+            }
 
-            // Add blank line between methods but not after the last one
-            if i < method_count - 1 {
-                output.push('\n');
+            let method_count = methods.len();
+            for (i, method) in methods.into_iter().enumerate() {
+                output.push_str(&method.render(&method_context));
+
+                // Add blank line between methods but not after the last one
+                if i < method_count - 1 || g < group_count - 1 {
+                    output.push('\n');
+                }
             }
         }
 
@@ -153,8 +221,20 @@ impl Render for ParsedStruct {
         output.push_str(&format!("{}}}\n", indent));
         output.push('\n');
 
-        // Render trait implementations
-        for trait_impl in &self.trait_impls {
+        if context.show_impl_headers {
+            for inherent_impl in &self.inherent_impls {
+                output.push_str(&inherent_impl.render(context));
+            }
+        }
+
+        // Render trait implementations, in index order unless asked to sort them
+        let mut trait_impls: Vec<&ParsedTraitImpl> = self.trait_impls.iter().collect();
+        if context.sort_impls {
+            trait_impls.sort_by(|a, b| {
+                (a.trait_name(), &a.trait_path).cmp(&(b.trait_name(), &b.trait_path))
+            });
+        }
+        for trait_impl in trait_impls {
             output.push_str(&trait_impl.render(context));
         }
 
@@ -162,6 +242,78 @@ impl Render for ParsedStruct {
     }
 }
 
+/// Render an impl's associated types or consts, each followed by a blank line
+/// unless it is the last item before the closing brace
+fn render_impl_members<T: Render>(members: &[T], more_follow: bool, context: &RenderContext) -> String {
+    let mut output = String::new();
+    for (i, member) in members.iter().enumerate() {
+        let rendered = member.render(context);
+        if i == members.len() - 1 && !more_follow {
+            output.push_str(rendered.strip_suffix('\n').unwrap_or(&rendered));
+        } else {
+            output.push_str(&rendered);
+        }
+    }
+    output
+}
+
+impl Render for ParsedAssocType {
+    fn render(&self, context: &RenderContext) -> String {
+        let indent = context.indent();
+        let mut output = DocRenderer.render_item_docs(self.docs.as_ref(), &indent, context);
+        output.push_str(&format!(
+            "{}{}type {} = {}\n\n",
+            indent,
+            TypeRenderer.render_visibility(&self.visibility),
+            self.name,
+            TypeRenderer.render_type(&self.ty)
+        ));
+        output
+    }
+}
+
+impl Render for ParsedInherentImpl {
+    fn render(&self, context: &RenderContext) -> String {
+        let mut output = String::new();
+        let indent = context.indent();
+        let type_renderer = TypeRenderer;
+
+        output.push_str(&DocRenderer.render_item_docs(self.docs.as_ref(), &indent, context));
+
+        let mut signature = format!(
+            "impl{} {}",
+            type_renderer.render_generics(&self.generics),
+            type_renderer.render_type(&self.for_type)
+        );
+        let where_clause =
+            type_renderer.render_where_clause_wrapped(&self.generics, &signature, &indent, context.width);
+        signature.push_str(&where_clause);
+
+        // A wrapped where clause leaves the brace on a line of its own
+        if where_clause.contains('\n') {
+            output.push_str(&format!("{}{}\n{}{{\n", indent, signature, indent));
+        } else {
+            output.push_str(&format!("{}{} {{\n", indent, signature));
+        }
+
+        let method_context = context.with_depth(context.depth + 1);
+        let more_follow = !self.consts.is_empty() || !self.methods.is_empty();
+        output.push_str(&render_impl_members(&self.assoc_types, more_follow, &method_context));
+        output.push_str(&render_impl_members(&self.consts, !self.methods.is_empty(), &method_context));
+        let method_count = self.methods.len();
+        for (i, method) in self.methods.iter().enumerate() {
+            output.push_str(&method.render(&method_context));
+            if i < method_count - 1 {
+                output.push('\n');
+            }
+        }
+
+        output.push_str(&format!("{}}}\n", indent));
+        output.push('\n');
+        output
+    }
+}
+
 impl Render for ParsedEnum {
     fn render(&self, context: &RenderContext) -> String {
         let mut output = String::new();
@@ -170,10 +322,15 @@ impl Render for ParsedEnum {
         let type_renderer = TypeRenderer;
 
         // Add deprecation notice before everything
-        output.push_str(&doc_renderer.render_deprecation(self.deprecation.as_ref(), &indent));
+        output.push_str(&doc_renderer.render_deprecation(self.deprecation.as_ref(), &indent, context));
 
         // Add docs after deprecation but before enum signature
-        output.push_str(&doc_renderer.render_docs(self.docs.as_ref(), &indent));
+        output.push_str(&doc_renderer.render_item_docs(self.docs.as_ref(), &indent, context));
+        output.push_str(&doc_renderer.render_aliases(&self.aliases, &indent, context));
+
+        if context.auto_traits && !context.no_auto_comment {
+            output.push_str(&doc_renderer.render_auto_traits(&self.auto_traits, &indent));
+        }
 
         let mut signature = String::new();
 
@@ -220,32 +377,56 @@ impl Render for ParsedVariant {
         let doc_renderer = DocRenderer;
         let type_renderer = TypeRenderer;
 
-        // Add docs first
-        output.push_str(&doc_renderer.render_docs(self.docs.as_ref(), &indent));
+        // Add deprecation notice first if present
+        output.push_str(&doc_renderer.render_deprecation(self.deprecation.as_ref(), &indent, context));
+
+        // Add docs after deprecation
+        output.push_str(&doc_renderer.render_item_docs(self.docs.as_ref(), &indent, context));
+
+        if self.non_exhaustive {
+            output.push_str(&format!("{}#[non_exhaustive]\n", indent));
+        }
 
         let mut signature = self.name.clone();
 
-        match &self.kind {
+        // Documented fields are laid out one per line so their docs can sit above them
+        let (fields, open, close) = match &self.kind {
             VariantKind::Unit => {
-                // No additional content needed
+                output.push_str(&format!("{}{}\n", indent, signature));
+                return output;
             }
-            VariantKind::Tuple(types) => {
-                signature.push('(');
-                let type_strs: Vec<String> = types.iter().map(|t| type_renderer.render_type(t)).collect();
-                signature.push_str(&type_strs.join(", "));
-                signature.push(')');
+            VariantKind::Tuple(fields) => (fields, "(", ")"),
+            VariantKind::Struct(fields) => (fields, " { ", " }"),
+        };
+        let is_struct = matches!(self.kind, VariantKind::Struct(_));
+        // Variant fields inherit the enum's visibility, so rustdoc normally reports them as
+        // `default` and nothing is printed
+        let render_field = |field: &ParsedField| {
+            let visibility = type_renderer.render_visibility(&field.visibility);
+            if is_struct {
+                format!("{}{}: {}", visibility, field.name, type_renderer.render_type(&field.field_type))
+            } else {
+                format!("{}{}", visibility, type_renderer.render_type(&field.field_type))
             }
-            VariantKind::Struct(fields) => {
-                signature.push_str(" { ");
-                let field_strs: Vec<String> = fields
-                    .iter()
-                    .map(|(name, ty)| format!("{}: {}", name, type_renderer.render_type(ty)))
-                    .collect();
-                signature.push_str(&field_strs.join(", "));
-                signature.push_str(" }");
+        };
+
+        if !context.signatures_only && fields.iter().any(|field| field.docs.is_some()) {
+            let field_indent = "  ".repeat(context.depth + 1);
+            signature.push_str(open.trim_end());
+            output.push_str(&format!("{}{}\n", indent, signature));
+            for field in fields {
+                output.push_str(&doc_renderer.render_item_docs(field.docs.as_ref(), &field_indent, context));
+                output.push_str(&format!("{}{},\n", field_indent, render_field(field)));
             }
+            output.push_str(&format!("{}{}\n", indent, close.trim_start()));
+            return output;
         }
 
+        let field_strs: Vec<String> = fields.iter().map(render_field).collect();
+        signature.push_str(open);
+        signature.push_str(&field_strs.join(", "));
+        signature.push_str(close);
+
         output.push_str(&format!("{}{}\n", indent, signature));
         output
     }
@@ -259,16 +440,26 @@ impl Render for ParsedTrait {
         let type_renderer = TypeRenderer;
 
         // Add deprecation notice first if present
-        output.push_str(&doc_renderer.render_deprecation(self.deprecation.as_ref(), &indent));
+        output.push_str(&doc_renderer.render_deprecation(self.deprecation.as_ref(), &indent, context));
 
         // Add docs after deprecation
-        output.push_str(&doc_renderer.render_docs(self.docs.as_ref(), &indent));
+        output.push_str(&doc_renderer.render_item_docs(self.docs.as_ref(), &indent, context));
+        output.push_str(&doc_renderer.render_aliases(&self.aliases, &indent, context));
+
+        // Downstream crates cannot implement traits with an unnameable supertrait
+        if self.is_sealed && !context.no_auto_comment {
+            output.push_str(&format!("{}// sealed trait\n", indent));
+        }
 
         let mut signature = String::new();
 
         // Add visibility
         signature.push_str(&type_renderer.render_visibility(&self.visibility));
 
+        if self.is_unsafe {
+            signature.push_str("unsafe ");
+        }
+
         signature.push_str("trait ");
         signature.push_str(&self.name);
 
@@ -333,22 +524,15 @@ impl Render for ParsedTraitItem {
         let type_renderer = TypeRenderer;
 
         match self {
-            ParsedTraitItem::AssocType { name, bounds, docs } => {
+            ParsedTraitItem::AssocType { name, bounds, default, docs } => {
                 let mut output = String::new();
                 
                 // Add docs first
-                output.push_str(&doc_renderer.render_docs(docs.as_ref(), &indent));
-
-                let mut signature = format!("type {}", name);
-
-                // TODO: Make associated type bounds more generic
-                if name == "Error" && bounds.is_empty() {
-                    signature.push_str(": std::error::Error");
-                } else if name == "Key" && bounds.is_empty() {
-                    signature.push_str(": Clone + Debug");
-                } else if !bounds.is_empty() {
-                    signature.push_str(": ");
-                    signature.push_str(&bounds.join(" + "));
+                output.push_str(&doc_renderer.render_item_docs(docs.as_ref(), &indent, context));
+
+                let mut signature = bounded(&format!("type {}", name), bounds);
+                if let Some(default) = default {
+                    signature.push_str(&format!(" = {}", type_renderer.render_type(default)));
                 }
 
                 output.push_str(&format!("{}{}\n", indent, signature));
@@ -358,7 +542,7 @@ impl Render for ParsedTraitItem {
                 let mut output = String::new();
                 
                 // Add docs first
-                output.push_str(&doc_renderer.render_docs(docs.as_ref(), &indent));
+                output.push_str(&doc_renderer.render_item_docs(docs.as_ref(), &indent, context));
 
                 let signature = format!("const {}: {}", name, type_renderer.render_type(ty));
                 output.push_str(&format!("{}{}\n", indent, signature));
@@ -369,14 +553,22 @@ impl Render for ParsedTraitItem {
                 let sig = &func.signature;
 
                 // Add deprecation notice first if present
-                output.push_str(&doc_renderer.render_deprecation(func.deprecation.as_ref(), &indent));
+                output.push_str(&doc_renderer.render_deprecation(func.deprecation.as_ref(), &indent, context));
 
                 // Add docs after deprecation
-                output.push_str(&doc_renderer.render_docs(func.docs.as_ref(), &indent));
+                output.push_str(&doc_renderer.render_item_docs(func.docs.as_ref(), &indent, context));
+                output.push_str(&doc_renderer.render_aliases(&func.aliases, &indent, context));
+
+                if context.show_perf_attrs {
+                    output.push_str(&AttrRenderer.render_perf_attrs(&func.attrs, &indent));
+                }
 
                 let mut signature = String::new();
 
                 // Skip visibility for trait methods
+                if sig.is_async {
+                    signature.push_str("async ");
+                }
                 signature.push_str("fn ");
                 signature.push_str(&sig.name);
 
@@ -409,7 +601,13 @@ impl Render for ParsedTraitItem {
                 }
 
                 // Add where clause if needed
-                signature.push_str(&type_renderer.render_where_clause(&sig.generics));
+                let where_clause = type_renderer.render_where_clause_wrapped(
+                    &sig.generics,
+                    &signature,
+                    &indent,
+                    context.width,
+                );
+                signature.push_str(&where_clause);
 
                 // Trait methods should have consistent indentation with other trait items
                 output.push_str(&format!("{}{}\n", indent, signature));
@@ -427,10 +625,11 @@ impl Render for ParsedConstant {
         let type_renderer = TypeRenderer;
 
         // Add deprecation notice first if present
-        output.push_str(&doc_renderer.render_deprecation(self.deprecation.as_ref(), &indent));
+        output.push_str(&doc_renderer.render_deprecation(self.deprecation.as_ref(), &indent, context));
 
         // Add docs after deprecation
-        output.push_str(&doc_renderer.render_docs(self.docs.as_ref(), &indent));
+        output.push_str(&doc_renderer.render_item_docs(self.docs.as_ref(), &indent, context));
+        output.push_str(&doc_renderer.render_aliases(&self.aliases, &indent, context));
 
         let mut signature = String::new();
 
@@ -442,6 +641,11 @@ impl Render for ParsedConstant {
         signature.push_str(": ");
         signature.push_str(&type_renderer.render_type(&self.ty));
 
+        if let Some(value) = &self.value {
+            signature.push_str(" = ");
+            signature.push_str(value);
+        }
+
         output.push_str(&format!("{}{}\n", indent, signature));
         output.push('\n');
 
@@ -449,17 +653,85 @@ impl Render for ParsedConstant {
     }
 }
 
-impl Render for ParsedModule {
+impl Render for ParsedTypeAlias {
     fn render(&self, context: &RenderContext) -> String {
         let mut output = String::new();
         let indent = context.indent();
         let doc_renderer = DocRenderer;
         let type_renderer = TypeRenderer;
 
-        // Add docs BEFORE the module signature (unlike structs/enums)
-        output.push_str(&doc_renderer.render_docs(self.docs.as_ref(), &indent));
+        // Add deprecation notice first if present
+        output.push_str(&doc_renderer.render_deprecation(self.deprecation.as_ref(), &indent, context));
+
+        // Add docs after deprecation
+        output.push_str(&doc_renderer.render_item_docs(self.docs.as_ref(), &indent, context));
+        output.push_str(&doc_renderer.render_aliases(&self.aliases, &indent, context));
+
+        let mut signature = String::new();
+
+        // Add visibility
+        signature.push_str(&type_renderer.render_visibility(&self.visibility));
+
+        signature.push_str("type ");
+        signature.push_str(&self.name);
+        signature.push_str(&type_renderer.render_generics(&self.generics));
+        signature.push_str(&type_renderer.render_where_clause(&self.generics));
+        signature.push_str(" = ");
+        signature.push_str(&type_renderer.render_type(&self.ty));
+
+        output.push_str(&format!("{}{}\n", indent, signature));
+        output.push('\n');
+
+        output
+    }
+}
+
+impl Render for ParsedStatic {
+    fn render(&self, context: &RenderContext) -> String {
+        let mut output = String::new();
+        let indent = context.indent();
+        let doc_renderer = DocRenderer;
+        let type_renderer = TypeRenderer;
+
+        // Add deprecation notice first if present
+        output.push_str(&doc_renderer.render_deprecation(self.deprecation.as_ref(), &indent, context));
+
+        // Add docs after deprecation
+        output.push_str(&doc_renderer.render_item_docs(self.docs.as_ref(), &indent, context));
+        output.push_str(&doc_renderer.render_aliases(&self.aliases, &indent, context));
+
+        let mut signature = String::new();
+
+        // Add visibility
+        signature.push_str(&type_renderer.render_visibility(&self.visibility));
+
+        signature.push_str("static ");
+        if self.mutable {
+            signature.push_str("mut ");
+        }
+        signature.push_str(&self.name);
+        signature.push_str(": ");
+        signature.push_str(&type_renderer.render_type(&self.ty));
+
+        if let Some(value) = &self.value {
+            signature.push_str(" = ");
+            signature.push_str(value);
+        }
+
+        output.push_str(&format!("{}{}\n", indent, signature));
+        output.push('\n');
+
+        output
+    }
+}
+
+impl Render for ParsedModule {
+    fn render(&self, context: &RenderContext) -> String {
+        let mut output = String::new();
+        let indent = context.indent();
+        let doc_renderer = DocRenderer;
+        let type_renderer = TypeRenderer;
 
-        // Then render the signature
         let mut signature = String::new();
 
         // Add visibility
@@ -469,12 +741,23 @@ impl Render for ParsedModule {
         signature.push_str(&self.name);
 
         output.push_str(&format!("{}{}\n", indent, signature));
+
+        // Module docs describe the contents, so they render as inner `//!` docs
+        let item_context = context.with_depth(context.depth + 1);
+        if !context.signatures_only {
+            output.push_str(&doc_renderer.render_inner_docs(self.docs.as_ref(), &item_context.indent()));
+        }
         output.push('\n');
 
         // Render module items
-        let item_context = context.with_depth(context.depth + 1);
-        for item in &self.items {
-            output.push_str(&item.render(&item_context));
+        if context.group_by_kind {
+            let items: Vec<&ParsedItem> = self.items.iter().collect();
+            output.push_str(&render_grouped_by_kind(&items, &item_context));
+        } else {
+            let items: Vec<&ParsedItem> = self.items.iter().collect();
+            for item in order_module_items(&items, &item_context) {
+                output.push_str(&item.render(&item_context));
+            }
         }
 
         output
@@ -488,10 +771,12 @@ impl Render for ParsedMacro {
         let doc_renderer = DocRenderer;
 
         // Add docs first
-        output.push_str(&doc_renderer.render_docs(self.docs.as_ref(), &indent));
+        output.push_str(&doc_renderer.render_item_docs(self.docs.as_ref(), &indent, context));
 
-        // Then render the macro signature
-        output.push_str(&format!("{}{}\n", indent, self.signature));
+        // Then the definition, one matcher arm per line
+        for line in self.signature.lines() {
+            output.push_str(&format!("{}{}\n", indent, line));
+        }
         output.push('\n');
 
         output
@@ -507,27 +792,38 @@ impl Render for ParsedTraitImpl {
 
         // Add docs or generate automatic documentation
         if let Some(docs) = &self.docs {
-            output.push_str(&doc_renderer.render_docs(Some(docs), &indent));
-        } else {
+            output.push_str(&doc_renderer.render_item_docs(Some(docs), &indent, context));
+        } else if !context.no_auto_comment && !context.signatures_only {
             // Generate automatic documentation for trait impls
+            // Named types are shortened to their last segment but keep their
+            // generic arguments; references, tuples and the like are spelled
+            // out as written
             let type_name = match &self.for_type {
-                RustType::Path { path, .. } => path.split("::").last().unwrap_or("Unknown"),
-                RustType::Generic(name) => name,
-                _ => "Unknown",
+                RustType::Path { path, generics } => type_renderer.render_type(&RustType::Path {
+                    path: path.split("::").last().unwrap_or("Unknown").to_string(),
+                    generics: generics.clone(),
+                }),
+                other => type_renderer.render_type(other),
             };
-            let trait_name = self
-                .trait_path
-                .split("::")
-                .last()
-                .unwrap_or(&self.trait_path);
             output.push_str(&format!(
                 "{}/// Implementation of {} trait for {}\n",
-                indent, trait_name, type_name
+                indent,
+                self.trait_name(),
+                type_name
             ));
         }
 
+        for predicate in &self.cfg {
+            output.push_str(&format!("{}#[cfg({})]\n", indent, predicate));
+        }
+
         let mut signature = String::new();
-        signature.push_str("impl ");
+        if self.is_unsafe {
+            signature.push_str("unsafe ");
+        }
+        signature.push_str("impl");
+        signature.push_str(&type_renderer.render_generics(&self.generics));
+        signature.push(' ');
 
         // TODO: Make trait path handling more generic
         if self.trait_path.ends_with("Protocol") {
@@ -538,17 +834,29 @@ impl Render for ParsedTraitImpl {
 
         signature.push_str(" for ");
         signature.push_str(&type_renderer.render_type(&self.for_type));
+        let where_clause =
+            type_renderer.render_where_clause_wrapped(&self.generics, &signature, &indent, context.width);
+        signature.push_str(&where_clause);
+
+        let inherited: &[ParsedFunction] = if context.show_inherited {
+            &self.inherited
+        } else {
+            &[]
+        };
 
         // Don't add braces for empty impls
-        if self.items.is_empty() {
+        if self.items.is_empty() && inherited.is_empty() {
             output.push_str(&format!("{}{}\n", indent, signature));
             output.push('\n');
             return output;
         }
 
-        // Normal impl with items
-        signature.push_str(" {");
-        output.push_str(&format!("{}{}\n", indent, signature));
+        // Normal impl with items; a wrapped where clause leaves the brace on a line of its own
+        if where_clause.contains('\n') {
+            output.push_str(&format!("{}{}\n{}{{\n", indent, signature, indent));
+        } else {
+            output.push_str(&format!("{}{} {{\n", indent, signature));
+        }
         output.push('\n');
 
         // Render all trait implementation items
@@ -563,6 +871,22 @@ impl Render for ParsedTraitImpl {
             }
         }
 
+        if !inherited.is_empty() {
+            if !self.items.is_empty() {
+                output.push('\n');
+            }
+            if !context.no_auto_comment {
+                output.push_str(&format!("{}// Provided by the trait\n", item_context.indent()));
+            }
+            let inherited_count = inherited.len();
+            for (i, method) in inherited.iter().enumerate() {
+                output.push_str(&ParsedTraitImplItem::Method(method.clone()).render(&item_context));
+                if i < inherited_count - 1 {
+                    output.push('\n');
+                }
+            }
+        }
+
         // Close curly brace
         output.push_str(&format!("{}}}\n", indent));
         output.push('\n');
@@ -579,13 +903,8 @@ impl Render for ParsedTraitImplItem {
 
         match self {
             ParsedTraitImplItem::AssocType { name, ty } => {
-                // TODO: Make special handling more generic
-                if name == "Error" {
-                    format!("{}type Error = HttpError\n", indent)
-                } else {
-                    let signature = format!("type {} = {}", name, type_renderer.render_type(ty));
-                    format!("{}{}\n", indent, signature)
-                }
+                let signature = format!("type {} = {}", name, type_renderer.render_type(ty));
+                format!("{}{}\n", indent, signature)
             }
             ParsedTraitImplItem::Method(func) => {
                 let mut output = String::new();
@@ -597,14 +916,22 @@ impl Render for ParsedTraitImplItem {
                 }
 
                 // Add deprecation notice first
-                output.push_str(&doc_renderer.render_deprecation(func.deprecation.as_ref(), &indent));
+                output.push_str(&doc_renderer.render_deprecation(func.deprecation.as_ref(), &indent, context));
 
                 // Add docs after deprecation
-                output.push_str(&doc_renderer.render_docs(func.docs.as_ref(), &indent));
+                output.push_str(&doc_renderer.render_item_docs(func.docs.as_ref(), &indent, context));
+                output.push_str(&doc_renderer.render_aliases(&func.aliases, &indent, context));
+
+                if context.show_perf_attrs {
+                    output.push_str(&AttrRenderer.render_perf_attrs(&func.attrs, &indent));
+                }
 
                 let mut signature = String::new();
 
                 // Skip visibility for trait methods
+                if sig.is_async {
+                    signature.push_str("async ");
+                }
                 signature.push_str("fn ");
                 signature.push_str(&sig.name);
 
@@ -658,6 +985,32 @@ impl Render for ParsedTraitImplItem {
     }
 }
 
+impl Render for ParsedReExport {
+    fn render(&self, context: &RenderContext) -> String {
+        let item = self.item.render(context);
+        if item.is_empty() {
+            return item;
+        }
+        let indent = context.indent();
+        let mut output = DocRenderer.render_item_docs(self.docs.as_ref(), &indent, context);
+        if !context.no_auto_comment {
+            output.push_str(&format!("{}// re-exported from {}\n", indent, self.source));
+        }
+        output.push_str(&item);
+        output
+    }
+}
+
+impl Render for ParsedTargeted {
+    fn render(&self, context: &RenderContext) -> String {
+        let item = self.item.render(context);
+        if item.is_empty() || context.no_auto_comment {
+            return item;
+        }
+        format!("{}// only on: {}\n{}", context.indent(), self.targets.join(", "), item)
+    }
+}
+
 impl Render for ParsedItem {
     fn render(&self, context: &RenderContext) -> String {
         match self {
@@ -670,9 +1023,13 @@ impl Render for ParsedItem {
             ParsedItem::Enum(en) => en.render(context),
             ParsedItem::Trait(tr) => tr.render(context),
             ParsedItem::Constant(c) => c.render(context),
+            ParsedItem::Static(s) => s.render(context),
+            ParsedItem::TypeAlias(t) => t.render(context),
             ParsedItem::Module(m) => m.render(context),
             ParsedItem::Macro(mac) => mac.render(context),
             ParsedItem::TraitImpl(impl_) => impl_.render(context),
+            ParsedItem::ReExport(reexport) => reexport.render(context),
+            ParsedItem::Targeted(targeted) => targeted.render(context),
         }
     }
 }
\ No newline at end of file
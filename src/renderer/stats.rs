@@ -0,0 +1,106 @@
+use crate::parser::*;
+use rustdoc_types::Visibility;
+use std::fmt;
+
+/// Item totals for a parsed crate, printed by `--stats`
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct CrateStats {
+    pub modules: usize,
+    pub structs: usize,
+    pub enums: usize,
+    pub traits: usize,
+    pub functions: usize,
+    pub type_aliases: usize,
+    pub constants: usize,
+    pub statics: usize,
+    pub macros: usize,
+    pub public: usize,
+    pub private: usize,
+}
+
+impl CrateStats {
+    /// Count the items below `module`; the crate root itself is not counted
+    pub fn collect(module: &ParsedModule) -> Self {
+        let mut stats = Self::default();
+        stats.add_items(&module.items);
+        stats
+    }
+
+    fn add_items(&mut self, items: &[ParsedItem]) {
+        for item in items {
+            let visibility = match item {
+                ParsedItem::Module(m) => {
+                    self.modules += 1;
+                    self.add_items(&m.items);
+                    Some(&m.visibility)
+                }
+                ParsedItem::Struct(s) => {
+                    self.structs += 1;
+                    Some(&s.visibility)
+                }
+                ParsedItem::Enum(e) => {
+                    self.enums += 1;
+                    Some(&e.visibility)
+                }
+                ParsedItem::Trait(t) => {
+                    self.traits += 1;
+                    Some(&t.visibility)
+                }
+                ParsedItem::Function(f) => {
+                    self.functions += 1;
+                    Some(&f.signature.visibility)
+                }
+                ParsedItem::TypeAlias(t) => {
+                    self.type_aliases += 1;
+                    Some(&t.visibility)
+                }
+                ParsedItem::Constant(c) => {
+                    self.constants += 1;
+                    Some(&c.visibility)
+                }
+                ParsedItem::Static(s) => {
+                    self.statics += 1;
+                    Some(&s.visibility)
+                }
+                ParsedItem::Macro(_) => {
+                    // Exported macros are always reachable from outside the crate
+                    self.macros += 1;
+                    Some(&Visibility::Public)
+                }
+                // The wrapped item counts itself, visibility included
+                ParsedItem::Targeted(t) => {
+                    self.add_items(std::slice::from_ref(t.item.as_ref()));
+                    None
+                }
+                // Inlined re-exports repeat an item that is counted where it's defined
+                ParsedItem::TraitImpl(_) | ParsedItem::ReExport(_) => None,
+            };
+
+            match visibility {
+                Some(Visibility::Public) => self.public += 1,
+                Some(_) => self.private += 1,
+                None => {}
+            }
+        }
+    }
+}
+
+impl fmt::Display for CrateStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "modules: {}, structs: {}, enums: {}, traits: {}, functions: {}, type_aliases: {}, constants: {}, statics: {}, macros: {} (public: {}, private: {})",
+            self.modules,
+            self.structs,
+            self.enums,
+            self.traits,
+            self.functions,
+            self.type_aliases,
+            self.constants,
+            self.statics,
+            self.macros,
+            self.public,
+            self.private
+        )
+    }
+}
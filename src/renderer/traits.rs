@@ -1,3 +1,5 @@
+use crate::renderer::components::WHERE_CLAUSE_WRAP_WIDTH;
+
 
 /// Configuration context for rendering operations
 #[derive(Debug, Clone)]
@@ -5,6 +7,50 @@ pub struct RenderContext {
     pub depth: usize,
     pub show_private: bool,
     pub format: OutputFormat,
+    /// Collapse low-signal detail such as private marker fields
+    pub compact: bool,
+    /// Summarise the auto traits implemented by each struct and enum
+    pub auto_traits: bool,
+    /// List associated functions without a `self` receiver apart from methods
+    pub group_assoc: bool,
+    /// Suppress every synthesized comment, leaving only authored docs and signatures
+    pub no_auto_comment: bool,
+    /// Show codegen attributes such as `#[inline]` and `#[cold]`, and `#[track_caller]`,
+    /// above functions
+    pub show_perf_attrs: bool,
+    /// Only show these doc sections (e.g. `Safety`) after each item's summary
+    pub sections: Option<Vec<String>>,
+    /// Group each module's items under per-kind headers instead of source order
+    pub group_by_kind: bool,
+    /// List trait default methods an impl inherits without overriding
+    pub show_inherited: bool,
+    /// Column limit for wrapping long signatures
+    pub width: usize,
+    /// Drop every doc comment, keeping signatures and deprecation notices
+    pub signatures_only: bool,
+    /// Render inherent impl blocks with their headers instead of inside the type body
+    pub show_impl_headers: bool,
+    /// Mark items deprecated after this version with
+    /// `CHANGED since <version> (deprecated in <version>)`
+    pub since: Option<String>,
+    /// Render a module's own items before descending into its submodules
+    pub breadth_first: bool,
+    /// Order each type's trait impls by trait name instead of index order
+    pub sort_impls: bool,
+    /// Stop after this many top-level items, noting how many were left out
+    pub max_items: Option<usize>,
+    /// Manifest details listed under the crate header
+    pub metadata: Option<CrateMetadata>,
+    /// Leave out the `# Crate:` title and the version and manifest lines under it
+    pub no_crate_header: bool,
+}
+
+/// Package details read from a local `Cargo.toml`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CrateMetadata {
+    pub license: Option<String>,
+    pub repository: Option<String>,
+    pub authors: Vec<String>,
 }
 
 impl RenderContext {
@@ -13,6 +59,23 @@ impl RenderContext {
             depth: 0,
             show_private: false,
             format: OutputFormat::Text,
+            compact: false,
+            auto_traits: false,
+            group_assoc: false,
+            no_auto_comment: false,
+            show_perf_attrs: false,
+            sections: None,
+            group_by_kind: false,
+            show_inherited: false,
+            width: WHERE_CLAUSE_WRAP_WIDTH,
+            signatures_only: false,
+            show_impl_headers: false,
+            since: None,
+            breadth_first: false,
+            sort_impls: false,
+            max_items: None,
+            metadata: None,
+            no_crate_header: false,
         }
     }
 
@@ -21,6 +84,23 @@ impl RenderContext {
             depth,
             show_private: self.show_private,
             format: self.format,
+            compact: self.compact,
+            auto_traits: self.auto_traits,
+            group_assoc: self.group_assoc,
+            no_auto_comment: self.no_auto_comment,
+            show_perf_attrs: self.show_perf_attrs,
+            sections: self.sections.clone(),
+            group_by_kind: self.group_by_kind,
+            show_inherited: self.show_inherited,
+            width: self.width,
+            signatures_only: self.signatures_only,
+            show_impl_headers: self.show_impl_headers,
+            since: self.since.clone(),
+            breadth_first: self.breadth_first,
+            sort_impls: self.sort_impls,
+            max_items: self.max_items,
+            metadata: self.metadata.clone(),
+            no_crate_header: self.no_crate_header,
         }
     }
 
@@ -36,9 +116,11 @@ impl Default for RenderContext {
 }
 
 /// Output format configuration
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
 pub enum OutputFormat {
     Text,
+    /// roff man page, rendered by `ManRenderer`
+    Man,
     // Future: Html, Markdown, etc.
 }
 
@@ -0,0 +1,371 @@
+#[cfg(test)]
+mod stdlib_tests {
+    use crate::error::DoccerError;
+    use crate::{load_stdlib_docs, resolve_stdlib_json_path};
+    use std::fs;
+    use std::path::Path;
+
+    #[test]
+    fn test_stdlib_json_path_override_bypasses_rustup_layout() {
+        // The override is returned verbatim, without consulting rustc for the host triple
+        let override_path = Path::new("/custom/location/std.json");
+        let resolved =
+            resolve_stdlib_json_path("std", Some("no-such-toolchain"), Some(override_path)).unwrap();
+        assert_eq!(resolved, override_path);
+
+        let json_path = std::env::temp_dir().join("doccer_stdlib_override_test.json");
+        fs::write(&json_path, "{\"format_version\": 53}").unwrap();
+        let content = load_stdlib_docs("core", None, Some(&json_path)).unwrap();
+        fs::remove_file(&json_path).unwrap();
+        assert_eq!(content, "{\"format_version\": 53}");
+    }
+
+    #[test]
+    fn test_stdlib_json_path_override_missing_file() {
+        let missing = Path::new("/nonexistent/doccer/std.json");
+        let err = load_stdlib_docs("std", None, Some(missing)).unwrap_err();
+        assert!(matches!(err, DoccerError::StdlibNotFound { crate_name: None, .. }));
+        let message = err.to_string();
+        assert!(message.contains("/nonexistent/doccer/std.json"));
+        assert!(!message.contains("rustup component add"));
+    }
+}
+
+#[cfg(test)]
+mod feature_tests {
+    use crate::error::DoccerError;
+    use crate::renderer::CrateMetadata;
+    use crate::{manifest_metadata, manifest_version, resolve_manifest_path, validate_features};
+    use std::path::Path;
+
+    fn attributes_manifest() -> &'static Path {
+        Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/attributes/Cargo.toml"))
+    }
+
+    #[test]
+    fn test_declared_features_pass_validation() {
+        let requested = vec!["advanced".to_string(), "fast".to_string(), "default".to_string()];
+        assert!(validate_features(attributes_manifest(), &requested).is_ok());
+    }
+
+    #[test]
+    fn test_unknown_feature_lists_available_features() {
+        let requested = vec!["advanced".to_string(), "advnced".to_string()];
+        let err = validate_features(attributes_manifest(), &requested).unwrap_err();
+        assert!(matches!(&err, DoccerError::UnknownFeatures { unknown, .. } if unknown == &["advnced"]));
+        let message = err.to_string();
+        assert!(message.contains("Unknown feature(s)"));
+        assert!(message.contains("advnced"));
+        assert!(message.contains("Available features: advanced, fast, optimize, unwind"));
+    }
+
+    #[test]
+    fn test_crate_version_read_from_local_manifest() {
+        // The deprecation fixture pins a non-default version so it can't match by accident
+        let fixture = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/deprecation"));
+        let manifest_path = resolve_manifest_path(fixture, None);
+        assert_eq!(manifest_version(&manifest_path).as_deref(), Some("2.0.0"));
+        assert_eq!(manifest_version(&fixture.join("missing/Cargo.toml")), None);
+    }
+
+    #[test]
+    fn test_metadata_read_from_local_manifest() {
+        let fixture = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/basic_types"));
+        let metadata = manifest_metadata(&resolve_manifest_path(fixture, None));
+        assert_eq!(
+            metadata,
+            Some(CrateMetadata {
+                license: Some("MIT OR Apache-2.0".to_string()),
+                repository: Some("https://github.com/example/basic_types".to_string()),
+                authors: vec!["Ada Example <ada@example.com>".to_string()],
+            })
+        );
+        assert_eq!(manifest_metadata(attributes_manifest()).map(|m| m.license), Some(None));
+    }
+}
+
+#[cfg(test)]
+mod docs_rs_tests {
+    use crate::error::DoccerError;
+    use crate::{
+        check_docs_rs_status, classify_missing_target, falls_back_to_older_release,
+        parse_platform_targets, select_json_capable_version, FallbackVersion,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn test_latest_falls_back_to_newest_json_capable_version() {
+        // The latest stable release predates JSON docs and only a pre-release has them,
+        // which is offered rather than used
+        let versions = json!({"versions": [
+            {"num": "2.0.0-beta.2", "created_at": "2025-07-01T10:00:00Z", "yanked": true},
+            {"num": "2.0.0-beta.1", "created_at": "2025-06-15T10:00:00Z", "yanked": false},
+            {"num": "1.4.0", "created_at": "2025-03-01T10:00:00Z", "yanked": false},
+            {"num": "1.3.0", "created_at": "2024-11-01T10:00:00Z", "yanked": false}
+        ]});
+        assert_eq!(
+            select_json_capable_version(&versions),
+            Some(FallbackVersion::PreRelease("2.0.0-beta.1".to_string()))
+        );
+
+        // A JSON-capable stable release wins over a newer pre-release
+        let versions = json!({"versions": [
+            {"num": "3.0.0-rc.1", "created_at": "2025-09-01T10:00:00Z", "yanked": false},
+            {"num": "2.1.0", "created_at": "2025-08-01T10:00:00Z", "yanked": false},
+            {"num": "2.0.0", "created_at": "2025-06-01T10:00:00Z", "yanked": false}
+        ]});
+        assert_eq!(
+            select_json_capable_version(&versions),
+            Some(FallbackVersion::Stable("2.0.0".to_string()))
+        );
+
+        // A JSON-capable latest that still 404'd falls back to the previous JSON-capable release
+        let versions = json!({"versions": [
+            {"num": "0.9.1", "created_at": "2025-08-02T10:00:00Z", "yanked": false},
+            {"num": "0.9.0", "created_at": "2025-06-01T10:00:00Z", "yanked": false},
+            {"num": "0.8.0", "created_at": "2025-01-01T10:00:00Z", "yanked": false}
+        ]});
+        assert_eq!(
+            select_json_capable_version(&versions),
+            Some(FallbackVersion::Stable("0.9.0".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_no_fallback_when_every_release_predates_json_docs() {
+        let versions = json!({"versions": [
+            {"num": "1.0.1", "created_at": "2024-05-01T10:00:00Z", "yanked": false},
+            {"num": "1.0.0", "created_at": "2023-05-01T10:00:00Z", "yanked": false}
+        ]});
+        assert_eq!(select_json_capable_version(&versions), None);
+    }
+
+    #[test]
+    fn test_missing_docs_are_reported_as_not_found() {
+        use reqwest::StatusCode;
+        let target = "x86_64-unknown-linux-gnu";
+
+        let err = check_docs_rs_status(StatusCode::NOT_FOUND, "serde", "0.1.0", target).unwrap_err();
+        assert!(matches!(
+            err,
+            DoccerError::NotFound { ref name, ref version, .. } if name == "serde" && version == "0.1.0"
+        ));
+
+        let err = check_docs_rs_status(StatusCode::BAD_GATEWAY, "serde", "latest", target).unwrap_err();
+        assert!(matches!(err, DoccerError::Network { .. }));
+        assert!(check_docs_rs_status(StatusCode::OK, "serde", "latest", target).is_ok());
+    }
+
+    #[test]
+    fn test_missing_target_is_told_apart_from_missing_version() {
+        let menu = r#"<ul class="pure-menu-list">
+            <li><a href="/crate/nix/0.30.1/target-redirect/x86_64-unknown-linux-gnu/nix/" class="pure-menu-link">x86_64-unknown-linux-gnu</a></li>
+            <li><a href="/crate/nix/0.30.1/target-redirect/aarch64-apple-darwin/nix/" class="pure-menu-link">aarch64-apple-darwin</a></li>
+            <li><a href="/crate/nix/0.30.1/target-redirect/x86_64-unknown-linux-gnu/nix/" class="pure-menu-link">x86_64-unknown-linux-gnu</a></li>
+        </ul>"#;
+        let available = parse_platform_targets(menu);
+        assert_eq!(available, ["x86_64-unknown-linux-gnu", "aarch64-apple-darwin"]);
+
+        let err = classify_missing_target("nix", "0.30.1", "x86_64-pc-windows-msvc", Some(available.clone()));
+        assert!(matches!(err, DoccerError::TargetNotBuilt { ref available, .. } if available.len() == 2));
+        assert!(err.to_string().contains("aarch64-apple-darwin"));
+
+        // A target that was built still 404'd, and an unknown version has no menu to probe
+        let err = classify_missing_target("nix", "0.30.1", "aarch64-apple-darwin", Some(available));
+        assert!(matches!(err, DoccerError::NotFound { .. }));
+        let err = classify_missing_target("nix", "9.9.9", "aarch64-apple-darwin", None);
+        assert!(matches!(err, DoccerError::NotFound { .. }));
+    }
+
+    #[test]
+    fn test_only_latest_without_json_falls_back() {
+        let built = Some(vec!["x86_64-unknown-linux-gnu".to_string()]);
+
+        // `latest` exists for the target but has no JSON, e.g. it predates the cutoff
+        let missing = classify_missing_target("nix", "latest", "x86_64-unknown-linux-gnu", built.clone());
+        assert!(falls_back_to_older_release("latest", &missing));
+
+        // An older release won't have a target the crate was never built for
+        let missing = classify_missing_target("nix", "latest", "x86_64-pc-windows-msvc", built);
+        assert!(matches!(missing, DoccerError::TargetNotBuilt { .. }));
+        assert!(!falls_back_to_older_release("latest", &missing));
+
+        // An explicitly requested version is never swapped for another
+        let missing = classify_missing_target("nix", "0.30.1", "x86_64-unknown-linux-gnu", None);
+        assert!(!falls_back_to_older_release("0.30.1", &missing));
+    }
+}
+
+#[cfg(test)]
+mod format_version_tests {
+    use crate::parse_json_with_context;
+
+    #[test]
+    fn test_unsupported_format_version_fails_before_deserializing() {
+        // The rest of the document doesn't match the Crate schema; the version check must
+        // report the real problem instead of a serde error
+        let json = r#"{"root": "not-an-id", "index": [], "format_version": 999}"#;
+        let message = parse_json_with_context(json, false, false).unwrap_err().to_string();
+        assert!(message.starts_with("Incompatible rustdoc JSON format version: found 999, expected 53."));
+        assert!(message.contains("Update doccer to a newer version"));
+        assert!(!message.contains("Failed to parse JSON documentation"));
+    }
+}
+
+#[cfg(test)]
+mod wrap_width_tests {
+    use crate::resolve_wrap_width;
+
+    #[test]
+    fn test_wrap_width_resolution() {
+        // Not a terminal: fall back to 80 columns
+        assert_eq!(resolve_wrap_width(None, true, None), 80);
+        assert_eq!(resolve_wrap_width(None, true, Some(132)), 132);
+        // An explicit width wins over detection
+        assert_eq!(resolve_wrap_width(Some(60), true, Some(132)), 60);
+        // Without --wrap the renderer default is kept
+        assert_eq!(resolve_wrap_width(None, false, Some(132)), 100);
+    }
+}
+
+#[cfg(test)]
+mod config_tests {
+    use crate::config::Config;
+    use crate::error::DoccerError;
+    use crate::renderer::OutputFormat;
+    use crate::Cli;
+    use clap::{CommandFactory, FromArgMatches};
+    use std::path::Path;
+
+    fn cli_with_config(args: &[&str], config: &str) -> Cli {
+        let matches = Cli::command().try_get_matches_from(args).expect("arguments should parse");
+        let mut cli = Cli::from_arg_matches(&matches).expect("arguments should parse");
+        let config = Config::parse(config, Path::new("doccer.toml")).expect("config should parse");
+        config.apply(&mut cli, &matches).expect("config should apply");
+        cli
+    }
+
+    #[test]
+    fn test_config_supplies_defaults_the_cli_leaves_out() {
+        let config = "format = \"man\"\ntarget = \"aarch64-apple-darwin\"\ngroup-by-kind = true\n";
+
+        let cli = cli_with_config(&["doccer", "serde"], config);
+        assert_eq!(cli.format, OutputFormat::Man);
+        assert_eq!(cli.target, "aarch64-apple-darwin");
+        assert!(cli.group_by_kind);
+
+        // Flags given on the command line win over the file
+        let args = ["doccer", "serde", "--format", "text", "-t", "x86_64-pc-windows-msvc"];
+        let cli = cli_with_config(&args, config);
+        assert_eq!(cli.format, OutputFormat::Text);
+        assert_eq!(cli.target, "x86_64-pc-windows-msvc");
+    }
+
+    #[test]
+    fn test_config_local_only_options_need_crate_path() {
+        let config = "include-private = true\nmetadata = true\n";
+
+        let cli = cli_with_config(&["doccer", "serde"], config);
+        assert!(!cli.include_private);
+        assert!(!cli.metadata);
+
+        let cli = cli_with_config(&["doccer", "--crate-path", "."], config);
+        assert!(cli.include_private);
+        assert!(cli.metadata);
+    }
+
+    #[test]
+    fn test_config_sets_wrapping_and_stdlib_json_path() {
+        let matches = Cli::command().try_get_matches_from(["doccer", "std::net"]).unwrap();
+        let mut cli = Cli::from_arg_matches(&matches).unwrap();
+        let config = "wrap = true\nwidth = 100\nstdlib-json-path = \"json/std.json\"\n";
+        let config = Config::parse(config, Path::new("project/doccer.toml")).unwrap();
+        config.apply(&mut cli, &matches).unwrap();
+
+        assert!(cli.wrap);
+        assert_eq!(cli.width, Some(100));
+        assert_eq!(cli.stdlib_json_path.as_deref(), Some(Path::new("project/json/std.json")));
+    }
+
+    #[test]
+    fn test_config_leaves_per_run_options_to_the_cli() {
+        for key in ["output-dir = \"docs\"", "stats = true", "check = true", "crate-path = \".\""] {
+            let err = Config::parse(key, Path::new("doccer.toml")).unwrap_err();
+            assert!(matches!(err, DoccerError::Config { .. }), "{} should be rejected", key);
+        }
+    }
+
+    #[test]
+    fn test_config_rejects_unknown_keys_and_formats() {
+        let err = Config::parse("colour = true\n", Path::new("doccer.toml")).unwrap_err();
+        assert!(matches!(err, DoccerError::Config { .. }));
+
+        let matches = Cli::command().try_get_matches_from(["doccer", "serde"]).unwrap();
+        let mut cli = Cli::from_arg_matches(&matches).unwrap();
+        let config = Config::parse("format = \"markdown\"\n", Path::new("doccer.toml")).unwrap();
+        let err = config.apply(&mut cli, &matches).unwrap_err();
+        assert!(err.to_string().contains("unknown format 'markdown'"));
+    }
+}
+
+#[cfg(test)]
+mod byte_format_tests {
+    use crate::format_bytes;
+
+    #[test]
+    fn test_exact_byte_counts_by_default() {
+        assert_eq!(format_bytes(0, false), "0 bytes");
+        assert_eq!(format_bytes(1_048_576, false), "1048576 bytes");
+        assert_eq!(format_bytes(3_221_225_472, false), "3221225472 bytes");
+    }
+
+    #[test]
+    fn test_human_sizes_use_iec_units() {
+        assert_eq!(format_bytes(512, true), "512 bytes");
+        assert_eq!(format_bytes(1024, true), "1.0 KiB");
+        assert_eq!(format_bytes(1536, true), "1.5 KiB");
+        assert_eq!(format_bytes(1_048_576, true), "1.0 MiB");
+        assert_eq!(format_bytes(3_221_225_472, true), "3.0 GiB");
+        assert_eq!(format_bytes(5 << 40, true), "5.0 TiB");
+    }
+}
+
+#[cfg(test)]
+mod json_dir_tests {
+    use crate::tests::pipeline_tests::json_pipeline_tests::build_crate;
+    use crate::{load_from_dir, parse_json_with_context, ItemParser, ParsedRenderer, RenderContext};
+    use std::fs;
+
+    /// A crate holding nothing but its root module
+    fn empty_crate_json(name: &str) -> String {
+        let mut crate_data = build_crate(&[], Vec::new());
+        crate_data.index.get_mut(&crate_data.root).unwrap().name = Some(name.to_string());
+        serde_json::to_string(&crate_data).unwrap()
+    }
+
+    #[test]
+    fn test_directory_renders_each_json_in_file_name_order() {
+        let dir = std::env::temp_dir().join("doccer_json_dir_test");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("zebra.json"), empty_crate_json("zebra")).unwrap();
+        fs::write(dir.join("aardvark.json"), empty_crate_json("aardvark")).unwrap();
+        fs::write(dir.join("README.md"), "not rustdoc JSON").unwrap();
+
+        let contents = load_from_dir(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(contents.len(), 2);
+
+        let output: String = contents
+            .iter()
+            .map(|content| {
+                let crate_data = parse_json_with_context(content, false, false).unwrap();
+                let module = ItemParser::new(&crate_data).parse_crate().unwrap();
+                ParsedRenderer.render(&module, crate_data.crate_version.as_deref(), &RenderContext::new())
+            })
+            .collect();
+        let aardvark = output.find("# Crate: aardvark").unwrap();
+        let zebra = output.find("# Crate: zebra").unwrap();
+        assert!(aardvark < zebra);
+    }
+}
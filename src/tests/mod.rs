@@ -1,53 +1,22 @@
+mod input_tests;
+mod pipeline_tests;
+
 #[cfg(test)]
 mod formatting_tests {
-    use serde_json::json;
-    use std::collections::HashMap;
-    use rustdoc_types::{Crate, Visibility, Deprecation, Id, Target};
-    use crate::{ParsedRenderer, ParsedFunction, FunctionSignature, RustType, Generics, GenericParam, GenericParamKind, ParsedTraitImplItem, ParsedTraitImpl, ParsedTraitItem, ParsedModule, ParsedStruct, ParsedItem, RenderContext, Render};
-
-    // Helper function to create minimal test items
-    fn create_test_item(kind: &str) -> serde_json::Value {
-        json!({
-            "id": "test::Item",
-            "crate_id": "test",
-            "name": "Item",
-            "kind": kind,
-            "inner": {},
-            "docs": "",
-            "links": {},
-            "attrs": {}
-        })
-    }
-
-    fn create_test_crate() -> Crate {
-        Crate {
-            root: Id(0),
-            crate_version: Some("0.1.0".to_string()),
-            includes_private: false,
-            index: HashMap::new(),
-            paths: HashMap::new(),
-            external_crates: HashMap::new(),
-            format_version: 53,
-            target: Target {
-                triple: "x86_64-unknown-linux-gnu".to_string(),
-                target_features: vec![],
-            },
-        }
-    }
-    
-    fn create_parsed_renderer() -> ParsedRenderer {
-        ParsedRenderer
-    }
+    use rustdoc_types::{Visibility, Deprecation};
+    use crate::{ParsedFunction, FunctionSignature, RustType, Generics, GenericParam, GenericParamKind, ParsedTraitImplItem, ParsedTraitImpl, ParsedTraitItem, ParsedModule, ParsedStruct, ParsedItem, ParsedVariant, ParsedField, VariantKind, RenderContext, Render};
 
     #[test]
     fn test_trait_impl_indentation() {
         // Test that trait implementations properly indent method signatures using the new ParsedRenderer
-        let renderer = create_parsed_renderer();
         let mut output = String::new();
         
         // Create a sample trait implementation
         let trait_impl = ParsedTraitImpl {
             trait_path: "Named".to_string(),
+            is_unsafe: false,
+            cfg: vec![],
+            inherited: vec![],
             for_type: RustType::Path { 
                 path: "Person".to_string(), 
                 generics: vec![] 
@@ -75,13 +44,17 @@ mod formatting_tests {
                                 inner: Box::new(RustType::Primitive("str".to_string())) 
                             },
                             is_async: false,
+                            abi: None,
                         },
                         docs: None,
                         deprecation: None,
+                        attrs: vec![],
+                        aliases: vec![],
                     }
                 )
             ],
             docs: Some("Implementation of Named trait for Person".to_string()),
+            generics: Generics { params: vec![], where_clauses: vec![] },
         };
         
         // Call the renderer function using the new trait-based approach
@@ -101,12 +74,14 @@ mod formatting_tests {
     #[test]
     fn test_trait_method_impl_indentation() {
         // Test indentation in a trait implementation with multiple methods
-        let renderer = create_parsed_renderer();
         let mut output = String::new();
         
         // Create a trait implementation with multiple methods
         let trait_impl = ParsedTraitImpl {
             trait_path: "Handler".to_string(),
+            is_unsafe: false,
+            cfg: vec![],
+            inherited: vec![],
             for_type: RustType::Path { 
                 path: "DefaultHandler".to_string(), 
                 generics: vec![] 
@@ -136,9 +111,12 @@ mod formatting_tests {
                                 ]
                             },
                             is_async: false,
+                            abi: None,
                         },
                         docs: None,
                         deprecation: None,
+                        attrs: vec![],
+                        aliases: vec![],
                     }
                 ),
                 ParsedTraitImplItem::Method(
@@ -164,16 +142,20 @@ mod formatting_tests {
                             ],
                             output: RustType::Unit,
                             is_async: false,
+                            abi: None,
                         },
                         docs: None,
                         deprecation: Some(Deprecation {
                             since: Some("1.2.5".to_string()),
                             note: None,
                         }),
+                        attrs: vec![],
+                        aliases: vec![],
                     }
                 )
             ],
             docs: None,
+            generics: Generics { params: vec![], where_clauses: vec![] },
         };
         
         // Call the renderer function using the new trait-based approach
@@ -202,12 +184,14 @@ mod formatting_tests {
     #[test]
     fn test_formatter_lifetime_param() {
         // Test that formatter parameters properly include lifetime annotations
-        let renderer = create_parsed_renderer();
         let mut output = String::new();
         
         // Create Debug trait implementation
         let trait_impl = ParsedTraitImpl {
             trait_path: "Debug".to_string(),
+            is_unsafe: false,
+            cfg: vec![],
+            inherited: vec![],
             for_type: RustType::Path { 
                 path: "HttpError".to_string(), 
                 generics: vec![] 
@@ -242,13 +226,17 @@ mod formatting_tests {
                                 generics: vec![]
                             },
                             is_async: false,
+                            abi: None,
                         },
                         docs: None,
                         deprecation: None,
+                        attrs: vec![],
+                        aliases: vec![],
                     }
                 )
             ],
             docs: None,
+            generics: Generics { params: vec![], where_clauses: vec![] },
         };
         
         // Call the renderer function using the new trait-based approach
@@ -266,12 +254,14 @@ mod formatting_tests {
     #[test]
     fn test_display_formatter_path() {
         // Test that Display trait formatter uses std::fmt path, not $crate
-        let renderer = create_parsed_renderer();
         let mut output = String::new();
         
         // Create Display trait implementation
         let trait_impl = ParsedTraitImpl {
             trait_path: "Display".to_string(),
+            is_unsafe: false,
+            cfg: vec![],
+            inherited: vec![],
             for_type: RustType::Path { 
                 path: "HttpError".to_string(), 
                 generics: vec![] 
@@ -306,13 +296,17 @@ mod formatting_tests {
                                 generics: vec![]
                             },
                             is_async: false,
+                            abi: None,
                         },
                         docs: None,
                         deprecation: None,
+                        attrs: vec![],
+                        aliases: vec![],
                     }
                 )
             ],
             docs: None,
+            generics: Generics { params: vec![], where_clauses: vec![] },
         };
         
         // Call the renderer function using the new trait-based approach
@@ -330,7 +324,6 @@ mod formatting_tests {
         let docs = "A macro for creating formatted messages\n\n# Examples\n\n```\nlet msg = format_message!(\"Hello\", \"World\");\nassert_eq!(msg, \"Hello: World\");\n```";
 
         let mut output = String::new();
-        let renderer = create_parsed_renderer();
         
         // Call the renderer function
         let doc_renderer = crate::renderer::components::DocRenderer;
@@ -357,7 +350,6 @@ mod formatting_tests {
     #[test]
     fn test_function_return_type() {
         // Test that function return types are not rendered with "-> ..." suffix
-        let renderer = create_parsed_renderer();
         let mut output = String::new();
         
         let func = ParsedFunction {
@@ -379,9 +371,12 @@ mod formatting_tests {
                 ],
                 output: RustType::Unit,
                 is_async: false,
+                abi: None,
             },
             docs: None,
             deprecation: None,
+            attrs: vec![],
+            aliases: vec![],
         };
         
         // Call the renderer function
@@ -396,7 +391,6 @@ mod formatting_tests {
     #[test]
     fn test_function_with_unit_return_type() {
         // Test function with explicit unit return type ()
-        let renderer = create_parsed_renderer();
         let mut output = String::new();
         
         let func = ParsedFunction {
@@ -417,9 +411,12 @@ mod formatting_tests {
                 ],
                 output: RustType::Unit,
                 is_async: false,  // Explicit unit type
+                abi: None,
             },
             docs: None,
             deprecation: None,
+            attrs: vec![],
+            aliases: vec![],
         };
         
         // Call the renderer function
@@ -434,7 +431,6 @@ mod formatting_tests {
     #[test]
     fn test_function_with_missing_return_type() {
         // Test function with completely missing return type (not even null)
-        let renderer = create_parsed_renderer();
         let mut output = String::new();
         
         let func = ParsedFunction {
@@ -459,9 +455,12 @@ mod formatting_tests {
                 ],
                 output: RustType::Unit,
                 is_async: false,  // Missing output means unit
+                abi: None,
             },
             docs: None,
             deprecation: None,
+            attrs: vec![],
+            aliases: vec![],
         };
         
         // Call the renderer function
@@ -476,7 +475,6 @@ mod formatting_tests {
     #[test]
     fn test_struct_with_where_clause() {
         // Test that structs with type constraints show proper where clauses
-        let renderer = create_parsed_renderer();
         let mut output = String::new();
         
         let struct_def = ParsedStruct {
@@ -492,6 +490,7 @@ mod formatting_tests {
                         name: "T".to_string(),
                         kind: GenericParamKind::Type {
                             bounds: vec!["Cacheable".to_string()],
+                            default: None,
                         },
                     }
                 ],
@@ -500,8 +499,13 @@ mod formatting_tests {
             fields: vec![],
             methods: vec![],  // Empty for test
             trait_impls: vec![],
+            auto_traits: vec![],
             docs: None,
             deprecation: None,
+            aliases: vec![],
+            inherent_impls: vec![],
+            consts: vec![],
+            assoc_types: vec![],
         };
         
         // Call the renderer function
@@ -517,7 +521,6 @@ mod formatting_tests {
     #[test]
     fn test_complex_struct_generics() {
         // Test a struct with multiple generic parameters and complex constraints
-        let renderer = create_parsed_renderer();
         let mut output = String::new();
         
         let struct_def = ParsedStruct {
@@ -534,6 +537,7 @@ mod formatting_tests {
                                 "PartialEq".to_string(),
                                 "std::hash::Hash".to_string()
                             ],
+                            default: None,
                         },
                     },
                     GenericParam {
@@ -543,6 +547,7 @@ mod formatting_tests {
                                 "Clone".to_string(),
                                 "Debug".to_string()
                             ],
+                            default: None,
                         },
                     }
                 ],
@@ -551,8 +556,13 @@ mod formatting_tests {
             fields: vec![],
             methods: vec![],  // Empty for test
             trait_impls: vec![],
+            auto_traits: vec![],
             docs: None,
             deprecation: None,
+            aliases: vec![],
+            inherent_impls: vec![],
+            consts: vec![],
+            assoc_types: vec![],
         };
         
         // Call the renderer function
@@ -566,18 +576,21 @@ mod formatting_tests {
     #[test]
     fn test_trait_impl_block_style() {
         // Test that trait implementations have proper syntax (with or without braces)
-        let renderer = create_parsed_renderer();
         let mut output = String::new();
         
         // Create an empty trait implementation
         let trait_impl = ParsedTraitImpl {
             trait_path: "Error".to_string(),
+            is_unsafe: false,
+            cfg: vec![],
+            inherited: vec![],
             for_type: RustType::Path { 
                 path: "HttpError".to_string(), 
                 generics: vec![] 
             },
             items: vec![],  // Empty items
             docs: None,
+            generics: Generics { params: vec![], where_clauses: vec![] },
         };
         
         // Call the renderer function using the new trait-based approach
@@ -593,35 +606,46 @@ mod formatting_tests {
     #[test]
     fn test_all_trait_impls_rendered() {
         // Test that all trait implementations are rendered, including StructuralPartialEq
-        let renderer = create_parsed_renderer();
         let mut output = String::new();
         
         // Create a module with multiple trait implementations
         let module = ParsedModule {
             name: "test".to_string(),
+            attrs: vec![],
             visibility: Visibility::Public,
             docs: None,
             items: vec![
                 ParsedItem::TraitImpl(ParsedTraitImpl {
                     trait_path: "Copy".to_string(),
+                    is_unsafe: false,
+                    cfg: vec![],
+                    inherited: vec![],
                     for_type: RustType::Path { 
                         path: "Point".to_string(), 
                         generics: vec![RustType::Generic("T".to_string())] 
                     },
                     items: vec![],
                     docs: None,
+                    generics: Generics { params: vec![], where_clauses: vec![] },
                 }),
                 ParsedItem::TraitImpl(ParsedTraitImpl {
                     trait_path: "StructuralPartialEq".to_string(),
+                    is_unsafe: false,
+                    cfg: vec![],
+                    inherited: vec![],
                     for_type: RustType::Path { 
                         path: "Point".to_string(), 
                         generics: vec![RustType::Generic("T".to_string())] 
                     },
                     items: vec![],
                     docs: None,
+                    generics: Generics { params: vec![], where_clauses: vec![] },
                 }),
                 ParsedItem::TraitImpl(ParsedTraitImpl {
                     trait_path: "PartialEq".to_string(),
+                    is_unsafe: false,
+                    cfg: vec![],
+                    inherited: vec![],
                     for_type: RustType::Path { 
                         path: "Point".to_string(), 
                         generics: vec![RustType::Generic("T".to_string())] 
@@ -653,13 +677,17 @@ mod formatting_tests {
                                     ],
                                     output: RustType::Primitive("bool".to_string()),
                                     is_async: false,
+                                    abi: None,
                                 },
                                 docs: None,
                                 deprecation: None,
+                                attrs: vec![],
+                                aliases: vec![],
                             }
                         )
                     ],
                     docs: None,
+                    generics: Generics { params: vec![], where_clauses: vec![] },
                 }),
             ],
         };
@@ -711,16 +739,18 @@ mod formatting_tests {
                 ],
                 output: RustType::Unit,
                 is_async: false,
+                abi: None,
             },
             docs: Some("Old method for setting timeout in seconds".to_string()),
             deprecation: Some(Deprecation {
                 since: Some("1.1.0".to_string()),
                 note: None,
             }),
+            attrs: vec![],
+            aliases: vec![],
         };
 
         let mut output = String::new();
-        let renderer = create_parsed_renderer();
         
         let context = RenderContext::new().with_depth(1);
         output.push_str(&func.render(&context));
@@ -729,11 +759,50 @@ mod formatting_tests {
         assert!(output.contains("  DEPRECATED since 1.1.0"));
         assert!(output.contains("pub fn set_timeout"));
     }
+
+    #[test]
+    fn test_since_marks_only_later_deprecations() {
+        use crate::renderer::components::is_newer_version;
+
+        assert!(is_newer_version("1.10.0", "1.9"));
+        assert!(is_newer_version("v2", "1.99.99"));
+        assert!(!is_newer_version("1.2", "1.2.0"));
+        assert!(!is_newer_version("1.3.0-beta", "1.0.0"));
+
+        let func = |since: &str| ParsedFunction {
+            signature: FunctionSignature {
+                name: "connect".to_string(),
+                visibility: Visibility::Public,
+                generics: Generics {
+                    params: vec![],
+                    where_clauses: vec![],
+                },
+                inputs: vec![],
+                output: RustType::Unit,
+                is_async: false,
+                abi: None,
+            },
+            docs: None,
+            deprecation: Some(Deprecation {
+                since: Some(since.to_string()),
+                note: None,
+            }),
+            attrs: vec![],
+            aliases: vec![],
+        };
+
+        let mut context = RenderContext::new().with_depth(1);
+        context.since = Some("1.2.0".to_string());
+        assert_eq!(
+            func("1.3.0").render(&context),
+            "  CHANGED since 1.2.0 (deprecated in 1.3.0)\n  DEPRECATED since 1.3.0\n  pub fn connect()\n"
+        );
+        assert_eq!(func("1.1.0").render(&context), "  DEPRECATED since 1.1.0\n  pub fn connect()\n");
+    }
     
     #[test]
     fn test_trait_with_deprecated_methods() {
         // Test rendering a trait with deprecated methods
-        let renderer = create_parsed_renderer();
         let mut output = String::new();
         
         // Create a trait with a deprecated method
@@ -760,12 +829,15 @@ mod formatting_tests {
                     ],
                     output: RustType::Unit,
                     is_async: false,
+                    abi: None,
                 },
                 docs: Some("Old way of handling errors".to_string()),
                 deprecation: Some(Deprecation {
                     since: Some("1.2.5".to_string()),
                     note: None,
                 }),
+                attrs: vec![],
+                aliases: vec![],
             }
         );
         
@@ -786,12 +858,14 @@ mod formatting_tests {
     #[test]
     fn test_trait_impl_with_deprecated_methods() {
         // Test rendering a trait implementation with deprecated methods
-        let renderer = create_parsed_renderer();
         let mut output = String::new();
         
         // Create a trait implementation with multiple methods, including deprecated ones
         let trait_impl = ParsedTraitImpl {
             trait_path: "Handler".to_string(),
+            is_unsafe: false,
+            cfg: vec![],
+            inherited: vec![],
             for_type: RustType::Path { 
                 path: "DefaultHandler".to_string(), 
                 generics: vec![] 
@@ -821,9 +895,12 @@ mod formatting_tests {
                                 ]
                             },
                             is_async: false,
+                            abi: None,
                         },
                         docs: None,
                         deprecation: None,
+                        attrs: vec![],
+                        aliases: vec![],
                     }
                 ),
                 ParsedTraitImplItem::Method(
@@ -849,16 +926,20 @@ mod formatting_tests {
                             ],
                             output: RustType::Unit,
                             is_async: false,
+                            abi: None,
                         },
                         docs: None,
                         deprecation: Some(Deprecation {
                             since: Some("1.2.5".to_string()),
                             note: None,
                         }),
+                        attrs: vec![],
+                        aliases: vec![],
                     }
                 )
             ],
             docs: None,
+            generics: Generics { params: vec![], where_clauses: vec![] },
         };
         
         // Call the renderer function using the new trait-based approach
@@ -885,4 +966,205 @@ mod formatting_tests {
         assert_eq!(process_line.chars().take(4).filter(|c| *c == ' ').count(), 4);
         assert_eq!(handle_error_line.chars().take(4).filter(|c| *c == ' ').count(), 4);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_long_where_clause_wraps_per_predicate() {
+        // Heavily-bounded signatures break each where predicate onto its own line
+        let func = ParsedFunction {
+            signature: FunctionSignature {
+                name: "with_context".to_string(),
+                visibility: Visibility::Public,
+                generics: Generics {
+                    params: vec![
+                        GenericParam {
+                            name: "T".to_string(),
+                            kind: GenericParamKind::Type { bounds: vec![], default: None },
+                        },
+                        GenericParam {
+                            name: "E".to_string(),
+                            kind: GenericParamKind::Type { bounds: vec![], default: None },
+                        },
+                    ],
+                    where_clauses: vec![
+                        "E: Error + Send + Sync + 'static".to_string(),
+                        "T: Clone".to_string(),
+                    ],
+                },
+                inputs: vec![
+                    ("result".to_string(), RustType::Path {
+                        path: "Result".to_string(),
+                        generics: vec![RustType::Generic("T".to_string()), RustType::Generic("E".to_string())],
+                    }),
+                    ("context".to_string(), RustType::Path { path: "String".to_string(), generics: vec![] }),
+                ],
+                output: RustType::Path {
+                    path: "Result".to_string(),
+                    generics: vec![
+                        RustType::Generic("T".to_string()),
+                        RustType::Path {
+                            path: "ContextualError".to_string(),
+                            generics: vec![RustType::Generic("E".to_string())],
+                        },
+                    ],
+                },
+                is_async: false,
+                abi: None,
+            },
+            docs: None,
+            deprecation: None,
+            attrs: vec![],
+            aliases: vec![],
+        };
+
+        let context = RenderContext::new().with_depth(1);
+        let output = func.render(&context);
+
+        assert!(output.contains("-> Result<T, ContextualError<E>>\n  where\n"));
+        assert!(output.contains("\n      E: Error + Send + Sync + 'static,\n"));
+        assert!(output.contains("\n      T: Clone,\n"));
+        assert!(!output.contains(" where E:"));
+
+        // Short signatures keep the where clause inline
+        let mut short = func.clone();
+        short.signature.inputs.clear();
+        short.signature.output = RustType::Unit;
+        short.signature.generics.where_clauses.truncate(1);
+        let output = short.render(&context);
+        assert!(output.contains("pub fn with_context<T, E>() where E: Error + Send + Sync + 'static\n"));
+    }
+
+    #[test]
+    fn test_struct_variant_field_docs() {
+        // Documented variant fields render one per line with their docs above them
+        let variant = ParsedVariant {
+            name: "Truck".to_string(),
+            kind: VariantKind::Struct(vec![ParsedField {
+                name: "capacity".to_string(),
+                visibility: Visibility::Default,
+                field_type: RustType::Primitive("f32".to_string()),
+                docs: Some("Maximum load in tons".to_string()),
+                deprecation: None,
+            }]),
+            docs: Some("A truck with cargo capacity in tons".to_string()),
+            deprecation: None,
+            non_exhaustive: false,
+        };
+
+        let context = RenderContext::new().with_depth(2);
+        let output = variant.render(&context);
+
+        assert_eq!(
+            output,
+            "    /// A truck with cargo capacity in tons\n    Truck {\n      /// Maximum load in tons\n      capacity: f32,\n    }\n"
+        );
+
+        // Undocumented tuple fields stay on a single line
+        let variant = ParsedVariant {
+            name: "Car".to_string(),
+            kind: VariantKind::Tuple(vec![ParsedField {
+                name: "0".to_string(),
+                visibility: Visibility::Default,
+                field_type: RustType::Primitive("u8".to_string()),
+                docs: None,
+                deprecation: None,
+            }]),
+            docs: None,
+            deprecation: None,
+            non_exhaustive: false,
+        };
+        assert_eq!(variant.render(&context), "    Car(u8)\n");
+    }
+    #[test]
+    fn test_visibility_rendering_is_centralized() {
+        // Every item kind renders rustdoc's `Visibility` through `TypeRenderer::render_visibility`;
+        // there is no parallel string-based variant to normalize
+        use crate::renderer::components::TypeRenderer;
+        let type_renderer = TypeRenderer;
+        let restricted = Visibility::Restricted {
+            parent: rustdoc_types::Id(0),
+            path: "super".to_string(),
+        };
+        assert_eq!(type_renderer.render_visibility(&Visibility::Public), "pub ");
+        assert_eq!(type_renderer.render_visibility(&Visibility::Crate), "pub(crate) ");
+        assert_eq!(type_renderer.render_visibility(&restricted), "pub(super) ");
+        let from_root = Visibility::Restricted {
+            parent: rustdoc_types::Id(3),
+            path: "::utils".to_string(),
+        };
+        assert_eq!(type_renderer.render_visibility(&from_root), "pub(in crate::utils) ");
+        assert_eq!(type_renderer.render_visibility(&Visibility::Default), "");
+
+        let module = ParsedModule {
+            name: "inner".to_string(),
+            attrs: vec![],
+            visibility: Visibility::Crate,
+            items: vec![],
+            docs: None,
+        };
+        assert_eq!(module.render(&RenderContext::new()), "pub(crate) mod inner\n\n");
+    }
+
+    #[test]
+    fn test_doc_sections_detected_and_filtered() {
+        use crate::renderer::components::{filter_doc_sections, DocRenderer};
+
+        // `Person::new` from the basic_types fixture; the `#` line inside the code block is not a heading
+        let docs = "Creates a new person\n\n# Examples\n\n```\n# use basic_types::Person;\nlet person = Person::new(\"Ada\".to_string(), 36);\n```\n# Panics\nNever panics";
+
+        let examples = filter_doc_sections(docs, &["examples".to_string()]);
+        assert!(examples.starts_with("Creates a new person\n\n# Examples\n"));
+        assert!(examples.contains("# use basic_types::Person;"));
+        assert!(!examples.contains("# Panics"));
+
+        assert_eq!(filter_doc_sections(docs, &["Safety".to_string()]), "Creates a new person");
+
+        // Headings get a blank doc line above them even when the source omits it
+        let rendered = DocRenderer.render_docs(Some(&docs.to_string()), "");
+        assert!(rendered.contains("/// ```\n///\n/// # Panics\n"));
+
+        let mut context = RenderContext::new();
+        context.sections = Some(vec!["Panics".to_string()]);
+        let rendered = DocRenderer.render_item_docs(Some(&docs.to_string()), "", &context);
+        assert_eq!(rendered, "/// Creates a new person\n///\n/// # Panics\n/// Never panics\n");
+    }
+
+    #[test]
+    fn test_trait_impl_for_reference_and_tuple_targets() {
+        let impl_for = |for_type: RustType| ParsedTraitImpl {
+            trait_path: "crate::Describe".to_string(),
+            is_unsafe: false,
+            cfg: vec![],
+            for_type,
+            items: vec![],
+            inherited: vec![],
+            docs: None,
+            generics: Generics { params: vec![], where_clauses: vec![] },
+        };
+        let context = RenderContext::new().with_depth(1);
+
+        let reference = impl_for(RustType::Reference {
+            lifetime: None,
+            mutable: false,
+            inner: Box::new(RustType::Path { path: "Bar".to_string(), generics: vec![] }),
+        });
+        assert_eq!(
+            reference.render(&context),
+            "  /// Implementation of Describe trait for &Bar\n  impl crate::Describe for &Bar\n\n"
+        );
+
+        let tuple = impl_for(RustType::Tuple(vec![
+            RustType::Generic("A".to_string()),
+            RustType::Generic("B".to_string()),
+        ]));
+        let output = tuple.render(&context);
+        assert!(output.contains("/// Implementation of Describe trait for (A, B)\n"));
+        assert!(output.contains("impl crate::Describe for (A, B)"));
+
+        let generic = impl_for(RustType::Path {
+            path: "crate::geometry::Point".to_string(),
+            generics: vec![RustType::Generic("T".to_string())],
+        });
+        let output = generic.render(&context);
+        assert!(output.contains("/// Implementation of Describe trait for Point<T>\n"));
+    }
+}
@@ -0,0 +1,2170 @@
+// Each test builds rustdoc JSON items, runs them through `ItemParser` and checks the
+// parsed tree or the text and man output it renders to
+#[cfg(test)]
+pub(crate) mod json_pipeline_tests {
+    use crate::{exclude_items, merge_targets, CrateStats, ItemParser, ManRenderer, ParsedItem, ParsedModule, ParsedRenderer, Render, RenderContext, VariantKind};
+    use rustdoc_types::{Crate, Visibility};
+    use serde_json::{json, Value};
+
+    /// Build a rustdoc item with the given id, name and `inner` payload
+    pub(crate) fn item(id: u32, name: &str, inner: Value) -> Value {
+        json!({
+            "id": id,
+            "crate_id": 0,
+            "name": name,
+            "span": null,
+            "visibility": "public",
+            "docs": null,
+            "links": {},
+            "attrs": [],
+            "deprecation": null,
+            "inner": inner
+        })
+    }
+
+    /// Build a crate whose root module (id 0) contains `root_items`
+    pub(crate) fn build_crate(root_items: &[u32], items: Vec<Value>) -> Crate {
+        let mut index = serde_json::Map::new();
+        index.insert(
+            "0".to_string(),
+            item(0, "test_crate", json!({"module": {"is_crate": true, "items": root_items, "is_stripped": false}})),
+        );
+        for item in items {
+            let id = item["id"].to_string();
+            index.insert(id, item);
+        }
+
+        serde_json::from_value(json!({
+            "root": 0,
+            "crate_version": "0.1.0",
+            "includes_private": false,
+            "index": index,
+            "paths": {},
+            "external_crates": {},
+            "target": {"triple": "x86_64-unknown-linux-gnu", "target_features": []},
+            "format_version": 53
+        }))
+        .expect("test crate should deserialize")
+    }
+
+    pub(crate) fn empty_generics() -> Value {
+        json!({"params": [], "where_predicates": []})
+    }
+
+    pub(crate) fn plain_struct(fields: &[u32]) -> Value {
+        json!({"struct": {
+            "kind": {"plain": {"fields": fields, "has_stripped_fields": false}},
+            "generics": empty_generics(),
+            "impls": []
+        }})
+    }
+
+    /// Build a free function with the given `[name, type]` inputs and header overrides
+    pub(crate) fn function(inputs: Value, output: Value, header: Value) -> Value {
+        let mut full_header = json!({
+            "is_const": false,
+            "is_unsafe": false,
+            "is_async": false,
+            "abi": "Rust"
+        });
+        if let (Some(full), Some(overrides)) = (full_header.as_object_mut(), header.as_object()) {
+            for (key, value) in overrides {
+                full.insert(key.clone(), value.clone());
+            }
+        }
+        json!({"function": {
+            "sig": {"inputs": inputs, "output": output, "is_c_variadic": false},
+            "generics": empty_generics(),
+            "header": full_header,
+            "has_body": true
+        }})
+    }
+
+    pub(crate) fn parse(crate_data: &Crate) -> ParsedModule {
+        ItemParser::new(crate_data).parse_crate().expect("crate should parse")
+    }
+
+    pub(crate) fn render_items(module: &ParsedModule, context: &RenderContext) -> String {
+        let context = context.with_depth(1);
+        module.items.iter().map(|item| item.render(&context)).collect()
+    }
+
+    #[test]
+    fn test_phantom_data_field_keeps_generic() {
+        let mut marker = item(2, "_marker", json!({"struct_field": {"resolved_path": {
+            "path": "PhantomData",
+            "id": 99,
+            "args": {"angle_bracketed": {"args": [{"type": {"generic": "T"}}], "constraints": []}}
+        }}}));
+        marker["visibility"] = json!("default");
+        let crate_data = build_crate(&[1], vec![item(1, "Tagged", plain_struct(&[2])), marker]);
+
+        let module = parse(&crate_data);
+        let ParsedItem::Struct(parsed) = &module.items[0] else {
+            panic!("expected a struct");
+        };
+        assert_eq!(parsed.fields[0].field_type.to_string(), "PhantomData<T>");
+
+        let output = render_items(&module, &RenderContext::new());
+        assert!(output.contains("    _marker: PhantomData<T>\n"));
+    }
+
+    #[test]
+    fn test_compact_collapses_private_marker_fields() {
+        let mut private_unit = item(3, "_private", json!({"struct_field": {"tuple": []}}));
+        private_unit["visibility"] = json!("default");
+        let mut phantom = item(4, "_phantom", json!({"struct_field": {"resolved_path": {
+            "path": "PhantomData",
+            "id": 99,
+            "args": {"angle_bracketed": {"args": [{"type": {"primitive": "u8"}}], "constraints": []}}
+        }}}));
+        phantom["visibility"] = json!("default");
+        let count = item(2, "count", json!({"struct_field": {"primitive": "u32"}}));
+        let crate_data = build_crate(
+            &[1],
+            vec![item(1, "Guard", plain_struct(&[2, 3, 4])), count, private_unit, phantom],
+        );
+        let module = parse(&crate_data);
+
+        let full = render_items(&module, &RenderContext::new());
+        assert!(full.contains("_private: ()"));
+        assert!(full.contains("_phantom: PhantomData<u8>"));
+
+        let mut context = RenderContext::new();
+        context.compact = true;
+        let compact = render_items(&module, &context);
+        assert!(compact.contains("    pub count: u32\n    // marker fields\n"));
+        assert_eq!(compact.matches("// marker fields").count(), 1);
+        assert!(!compact.contains("_private"));
+        assert!(!compact.contains("_phantom"));
+    }
+
+    fn trait_with_supertrait(supertrait_id: u32, path: &str) -> Value {
+        json!({"trait": {
+            "is_auto": false,
+            "is_unsafe": false,
+            "is_dyn_compatible": true,
+            "items": [],
+            "generics": empty_generics(),
+            "bounds": [{"trait_bound": {
+                "trait": {"path": path, "id": supertrait_id, "args": null},
+                "generic_params": [],
+                "modifier": "none"
+            }}],
+            "implementations": []
+        }})
+    }
+
+    fn marker_trait() -> Value {
+        json!({"trait": {
+            "is_auto": false,
+            "is_unsafe": false,
+            "is_dyn_compatible": true,
+            "items": [],
+            "generics": empty_generics(),
+            "bounds": [],
+            "implementations": []
+        }})
+    }
+
+    #[test]
+    fn test_sealed_trait_annotation() {
+        // `Sealed` lives in the index but no public module exposes it, while
+        // `Named` sits at the crate root
+        let crate_data = build_crate(
+            &[1, 3, 4],
+            vec![
+                item(1, "SealedShape", trait_with_supertrait(2, "private::Sealed")),
+                item(2, "Sealed", marker_trait()),
+                item(3, "OpenShape", trait_with_supertrait(4, "Named")),
+                item(4, "Named", marker_trait()),
+            ],
+        );
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("  // sealed trait\n  pub trait SealedShape {"));
+        assert!(!output.contains("// sealed trait\n  pub trait OpenShape"));
+        assert_eq!(output.matches("// sealed trait").count(), 1);
+    }
+
+    #[test]
+    fn test_extern_abi_rendered_verbatim() {
+        let crate_data = build_crate(
+            &[1, 2, 3, 4],
+            vec![
+                item(1, "system_call", function(json!([]), Value::Null, json!({"abi": {"System": {"unwind": false}}}))),
+                item(2, "unwinding", function(json!([]), Value::Null, json!({"abi": {"C": {"unwind": true}}}))),
+                item(3, "firmware", function(json!([]), Value::Null, json!({"abi": {"Other": "\"efiapi\""}}))),
+                item(4, "plain", function(json!([]), Value::Null, json!({}))),
+            ],
+        );
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("  pub extern \"system\" fn system_call()\n"));
+        assert!(output.contains("  pub extern \"C-unwind\" fn unwinding()\n"));
+        assert!(output.contains("  pub extern \"efiapi\" fn firmware()\n"));
+        assert!(output.contains("  pub fn plain()\n"));
+    }
+
+    #[test]
+    fn test_man_format_sections_and_entries() {
+        let mut point = item(1, "Point", plain_struct(&[]));
+        point["docs"] = json!("A point on the plane");
+        let crate_data = build_crate(
+            &[1, 2],
+            vec![
+                point,
+                item(2, "origin", function(json!([]), json!({"primitive": "i32"}), json!({}))),
+            ],
+        );
+
+        let output = ManRenderer.render(&parse(&crate_data), Some("0.1.0"), &RenderContext::new());
+        assert!(output.starts_with(".TH \"test_crate\" 3"));
+        assert!(output.contains(".SH STRUCTS\n.TP\n.B \"pub struct Point\"\nA point on the plane\n"));
+        assert!(output.contains(".SH FUNCTIONS\n.TP\n.B \"pub fn origin() \\-> i32\"\n"));
+    }
+
+    /// `T: Debug`, as a where predicate
+    fn debug_where_predicate() -> Value {
+        json!([{"bound_predicate": {
+            "type": {"generic": "T"},
+            "bounds": [{"trait_bound": {
+                "trait": {"path": "Debug", "id": 0, "args": null},
+                "generic_params": [],
+                "modifier": "none"
+            }}],
+            "generic_params": []
+        }}])
+    }
+
+    #[test]
+    fn test_man_wrapped_where_clause_stays_unfilled() {
+        let mut dump = function(json!([["value", {"generic": "T"}]]), Value::Null, json!({}));
+        dump["function"]["generics"]["params"] = json!([{"name": "T", "kind": {"type": {
+            "bounds": [], "default": null, "is_synthetic": false
+        }}}]);
+        dump["function"]["generics"]["where_predicates"] = debug_where_predicate();
+        let mut dump = item(1, "dump", dump);
+        dump["docs"] = json!("Prints the value");
+        let crate_data = build_crate(&[1], vec![dump]);
+
+        let mut context = RenderContext::new();
+        context.width = 20;
+        let output = ManRenderer.render(&parse(&crate_data), None, &context);
+        assert!(output.contains(
+            ".TP\n.B \"pub fn dump<T>(value: T)\"\n.nf\nwhere\n    T: Debug,\n.fi\nPrints the value\n"
+        ));
+    }
+
+    #[test]
+    fn test_man_type_entries_keep_where_clauses() {
+        let generics = json!({
+            "params": [{"name": "T", "kind": {"type": {"bounds": [], "default": null, "is_synthetic": false}}}],
+            "where_predicates": debug_where_predicate()
+        });
+        let mut wrapper = plain_struct(&[]);
+        wrapper["struct"]["generics"] = generics.clone();
+        let mut printable = marker_trait();
+        printable["trait"]["generics"] = generics;
+        let crate_data = build_crate(&[1, 2], vec![item(1, "Wrapper", wrapper), item(2, "Printable", printable)]);
+
+        let output = ManRenderer.render(&parse(&crate_data), None, &RenderContext::new());
+        assert!(output.contains(".B \"pub struct Wrapper<T> where T: Debug\"\n"));
+        assert!(output.contains(".B \"pub trait Printable<T> where T: Debug\"\n"));
+    }
+
+    #[test]
+    fn test_man_function_tag_is_signature_despite_aliases() {
+        let mut origin = item(1, "origin", function(json!([]), json!({"primitive": "i32"}), json!({})));
+        origin["attrs"] = json!(["#[doc(alias = \"zero\")]"]);
+        let crate_data = build_crate(&[1], vec![origin]);
+
+        let output = ManRenderer.render(&parse(&crate_data), None, &RenderContext::new());
+        assert!(output.contains(".SH FUNCTIONS\n.TP\n.B \"pub fn origin() \\-> i32\"\n"));
+        assert!(!output.contains("aliases"));
+    }
+
+    #[test]
+    fn test_man_function_tag_is_signature_with_perf_attrs() {
+        let mut force_inline = item(1, "force_inline", function(json!([]), Value::Null, json!({})));
+        force_inline["attrs"] = json!(["#[inline(always)]"]);
+        let crate_data = build_crate(&[1], vec![force_inline]);
+
+        let mut context = RenderContext::new();
+        context.show_perf_attrs = true;
+        let output = ManRenderer.render(&parse(&crate_data), None, &context);
+        assert!(output.contains(".SH FUNCTIONS\n.TP\n.B \"pub fn force_inline()\"\n"));
+        assert!(!output.contains("#[inline"));
+    }
+
+    #[test]
+    fn test_man_signatures_only_drops_docs() {
+        let mut point = item(1, "Point", plain_struct(&[]));
+        point["docs"] = json!("A point on the plane");
+        let mut crate_data = build_crate(&[1], vec![point]);
+        crate_data.index.get_mut(&crate_data.root).unwrap().docs = Some("Geometry helpers".to_string());
+        let module = parse(&crate_data);
+        assert!(ManRenderer.render(&module, None, &RenderContext::new()).contains(".SH DESCRIPTION\n"));
+
+        let mut context = RenderContext::new();
+        context.signatures_only = true;
+        let output = ManRenderer.render(&module, None, &context);
+        assert!(output.contains(".SH STRUCTS\n.TP\n.B \"pub struct Point\"\n"));
+        assert!(!output.contains(".SH DESCRIPTION"));
+        assert!(!output.contains("A point on the plane"));
+    }
+
+    #[test]
+    fn test_man_sections_filter_entry_docs() {
+        let mut parse_fn = item(1, "parse", function(json!([]), Value::Null, json!({})));
+        parse_fn["docs"] = json!("Parses input\n\n# Examples\n\nparse();\n\n# Panics\n\nOn empty input");
+        let crate_data = build_crate(&[1], vec![parse_fn]);
+
+        let mut context = RenderContext::new();
+        context.sections = Some(vec!["Panics".to_string()]);
+        let output = ManRenderer.render(&parse(&crate_data), None, &context);
+        assert!(output.contains("Parses input\n"));
+        assert!(output.contains("On empty input\n"));
+        assert!(!output.contains("Examples"));
+    }
+
+    /// Build a trait impl of `trait_path` for the struct with id `for_id`
+    fn auto_trait_impl(trait_path: &str, for_id: u32, is_negative: bool) -> Value {
+        json!({"impl": {
+            "is_unsafe": false,
+            "generics": empty_generics(),
+            "provided_trait_methods": [],
+            "trait": {"path": trait_path, "id": 90, "args": null},
+            "for": {"resolved_path": {"path": "Handle", "id": for_id, "args": null}},
+            "items": [],
+            "is_negative": is_negative,
+            "is_synthetic": true,
+            "blanket_impl": null
+        }})
+    }
+
+    #[test]
+    fn test_auto_traits_summary_only_with_flag() {
+        let mut handle = plain_struct(&[]);
+        handle["struct"]["impls"] = json!([2, 3, 4]);
+        let crate_data = build_crate(
+            &[1],
+            vec![
+                item(1, "Handle", handle),
+                item(2, "", auto_trait_impl("Sync", 1, false)),
+                item(3, "", auto_trait_impl("Send", 1, false)),
+                item(4, "", auto_trait_impl("Unpin", 1, true)),
+            ],
+        );
+        let module = parse(&crate_data);
+
+        let default = render_items(&module, &RenderContext::new());
+        assert!(!default.contains("// auto:"));
+        assert!(!default.contains("impl Send"));
+
+        let mut context = RenderContext::new();
+        context.auto_traits = true;
+        let output = render_items(&module, &context);
+        assert!(output.contains("  // auto: Send, Sync\n  pub struct Handle {"));
+    }
+
+    #[test]
+    fn test_constant_value_rendered() {
+        // Mirrors `MAX_USERS` from the basic_types fixture
+        let crate_data = build_crate(
+            &[1, 2],
+            vec![
+                item(1, "MAX_USERS", json!({"constant": {
+                    "type": {"primitive": "usize"},
+                    "const": {"expr": "1000", "value": "1_000usize", "is_literal": true}
+                }})),
+                item(2, "TABLE_SIZE", json!({"constant": {
+                    "type": {"primitive": "usize"},
+                    "const": {"expr": "_", "value": "4_096usize", "is_literal": false}
+                }})),
+            ],
+        );
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("  pub const MAX_USERS: usize = 1000\n"));
+        assert!(output.contains("  pub const TABLE_SIZE: usize = 4_096usize\n"));
+    }
+
+    #[test]
+    fn test_module_docs_render_as_inner_docs() {
+        // Mirrors the `network` module from the modules fixture
+        let mut network = item(1, "network", json!({"module": {"is_crate": false, "items": [2], "is_stripped": false}}));
+        network["docs"] = json!("Network-related functionality");
+        let mut connect = item(2, "connect", function(json!([]), Value::Null, json!({})));
+        connect["docs"] = json!("Opens a connection");
+        let mut crate_data = build_crate(&[1], vec![network, connect]);
+        crate_data.index.get_mut(&crate_data.root).unwrap().docs = Some("Modules fixture".to_string());
+
+        let module = parse(&crate_data);
+        let output = ParsedRenderer.render(&module, None, &RenderContext::new());
+        assert!(output.contains("# Crate: test_crate\n\n//! Modules fixture\n"));
+        assert!(output.contains("  pub mod network\n    //! Network-related functionality\n"));
+        assert!(output.contains("    /// Opens a connection\n    pub fn connect()\n"));
+    }
+
+    #[test]
+    fn test_group_assoc_separates_receiverless_functions() {
+        // Mirrors `Person` from the basic_types fixture
+        let mut person = plain_struct(&[]);
+        person["struct"]["impls"] = json!([2]);
+        let inherent = item(2, "", json!({"impl": {
+            "is_unsafe": false,
+            "generics": empty_generics(),
+            "provided_trait_methods": [],
+            "trait": null,
+            "for": {"resolved_path": {"path": "Person", "id": 1, "args": null}},
+            "items": [3, 4],
+            "is_negative": false,
+            "is_synthetic": false,
+            "blanket_impl": null
+        }}));
+        let new = item(3, "new", function(
+            json!([["name", {"primitive": "str"}]]),
+            json!({"generic": "Self"}),
+            json!({}),
+        ));
+        let get_name = item(4, "get_name", function(
+            json!([["self", {"borrowed_ref": {"lifetime": null, "is_mutable": false, "type": {"generic": "Self"}}}]]),
+            json!({"primitive": "u32"}),
+            json!({}),
+        ));
+        let crate_data = build_crate(&[1], vec![item(1, "Person", person), inherent, new, get_name]);
+        let module = parse(&crate_data);
+
+        let default = render_items(&module, &RenderContext::new());
+        assert!(!default.contains("// Associated functions"));
+
+        let mut context = RenderContext::new();
+        context.group_assoc = true;
+        let output = render_items(&module, &context);
+        let assoc = output.find("// Associated functions").expect("associated group");
+        let methods = output.find("// Methods").expect("methods group");
+        let new_pos = output.find("fn new(").unwrap();
+        let get_name_pos = output.find("fn get_name(&self)").unwrap();
+        assert!(assoc < new_pos && new_pos < methods && methods < get_name_pos);
+    }
+
+    #[test]
+    fn test_trait_impl_assoc_type_renders_full_rhs() {
+        // `type Error` used to be hardcoded to `HttpError`; both names must come from real data
+        let path = |name: &str, args: Vec<Value>| json!({"resolved_path": {
+            "path": name,
+            "id": 99,
+            "args": {"angle_bracketed": {"args": args, "constraints": []}}
+        }});
+        let output_ty = path("Result", vec![
+            json!({"type": path("Vec", vec![json!({"type": {"generic": "T"}})])}),
+            json!({"type": {"borrowed_ref": {"lifetime": "'a", "is_mutable": false, "type": {"primitive": "str"}}}}),
+        ]);
+        let assoc = |id: u32, name: &str, ty: Value| item(id, name, json!({"assoc_type": {
+            "generics": empty_generics(),
+            "bounds": [],
+            "type": ty
+        }}));
+        let transform_impl = item(1, "", json!({"impl": {
+            "is_unsafe": false,
+            "generics": empty_generics(),
+            "provided_trait_methods": [],
+            "trait": {"path": "Transform", "id": 98, "args": null},
+            "for": path("Wrapper", vec![json!({"type": {"generic": "T"}})]),
+            "items": [2, 3],
+            "is_negative": false,
+            "is_synthetic": false,
+            "blanket_impl": null
+        }}));
+        let crate_data = build_crate(
+            &[1],
+            vec![
+                transform_impl,
+                assoc(2, "Output", output_ty),
+                assoc(3, "Error", path("ParseError", vec![json!({"type": {"generic": "T"}})])),
+            ],
+        );
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("type Output = Result<Vec<T>, &'a str>\n"));
+        assert!(output.contains("type Error = ParseError<T>\n"));
+    }
+
+    #[test]
+    fn test_no_auto_comment_suppresses_synthesized_lines() {
+        let mut handle = plain_struct(&[]);
+        handle["struct"]["impls"] = json!([4, 5]);
+        let mut display_impl = auto_trait_impl("Display", 3, false);
+        display_impl["impl"]["is_synthetic"] = json!(false);
+        let mut sealed_shape = item(1, "SealedShape", trait_with_supertrait(2, "private::Sealed"));
+        sealed_shape["docs"] = json!("A shape only this crate can implement");
+        let crate_data = build_crate(
+            &[1, 3],
+            vec![
+                sealed_shape,
+                item(2, "Sealed", marker_trait()),
+                item(3, "Handle", handle),
+                item(4, "", auto_trait_impl("Send", 3, false)),
+                item(5, "", display_impl),
+            ],
+        );
+        let module = parse(&crate_data);
+
+        let mut context = RenderContext::new();
+        context.auto_traits = true;
+        let annotated = render_items(&module, &context);
+        assert!(annotated.contains("// sealed trait"));
+        assert!(annotated.contains("// auto: Send"));
+        assert!(annotated.contains("/// Implementation of Display trait for Handle"));
+
+        context.no_auto_comment = true;
+        let output = render_items(&module, &context);
+        let comments: Vec<&str> = output
+            .lines()
+            .map(str::trim_start)
+            .filter(|line| line.starts_with("//"))
+            .collect();
+        assert_eq!(comments, vec!["/// A shape only this crate can implement"]);
+        assert!(output.contains("impl Display for Handle"));
+    }
+
+    #[test]
+    fn test_unsafe_trait_and_impl_keyword() {
+        let mut raw_buffer = marker_trait();
+        raw_buffer["trait"]["is_unsafe"] = json!(true);
+        let mut buffer_impl = auto_trait_impl("RawBuffer", 3, false);
+        buffer_impl["impl"]["is_unsafe"] = json!(true);
+        buffer_impl["impl"]["is_synthetic"] = json!(false);
+        let mut handle = plain_struct(&[]);
+        handle["struct"]["impls"] = json!([2]);
+        let crate_data = build_crate(
+            &[1, 3, 4],
+            vec![
+                item(1, "RawBuffer", raw_buffer),
+                item(2, "", buffer_impl),
+                item(3, "Handle", handle),
+                item(4, "Named", marker_trait()),
+            ],
+        );
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("  pub unsafe trait RawBuffer {"));
+        assert!(output.contains("  unsafe impl RawBuffer for Handle\n"));
+        assert!(output.contains("  pub trait Named {"));
+    }
+
+    #[test]
+    fn test_generic_param_default_renders_after_bounds() {
+        // Mirrors `Wrapper<T = u32>` from the generics fixture, plus a bounded variant
+        let param = |name: &str, bounds: Value| json!({
+            "name": name,
+            "kind": {"type": {"bounds": bounds, "default": {"primitive": "u32"}, "is_synthetic": false}}
+        });
+        let clone_bound = json!([{"trait_bound": {
+            "trait": {"path": "Clone", "id": 99, "args": null},
+            "generic_params": [],
+            "modifier": "none"
+        }}]);
+        let mut wrapper = plain_struct(&[]);
+        wrapper["struct"]["generics"]["params"] = json!([param("T", json!([]))]);
+        let mut bounded = plain_struct(&[]);
+        bounded["struct"]["generics"]["params"] = json!([param("T", clone_bound)]);
+        let crate_data = build_crate(&[1, 2], vec![item(1, "Wrapper", wrapper), item(2, "Bounded", bounded)]);
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("  pub struct Wrapper<T = u32> {"));
+        assert!(output.contains("  pub struct Bounded<T: Clone = u32> {"));
+    }
+
+    #[test]
+    fn test_perf_attrs_render_only_with_flag() {
+        // Mirrors `force_inline`, `error_handler` and `expect_positive` from the attributes fixture
+        let mut force_inline = item(1, "force_inline", function(json!([]), Value::Null, json!({})));
+        force_inline["attrs"] = json!(["#[inline(always)]", "#[must_use]"]);
+        let mut error_handler = item(2, "error_handler", function(json!([]), Value::Null, json!({})));
+        error_handler["attrs"] = json!(["#[attr = Cold]"]);
+        let mut expect_positive = item(3, "expect_positive", function(json!([]), Value::Null, json!({})));
+        expect_positive["attrs"] = json!(["#[attr = TrackCaller]"]);
+        let module = parse(&build_crate(&[1, 2, 3], vec![force_inline, error_handler, expect_positive]));
+
+        let default = render_items(&module, &RenderContext::new());
+        assert!(!default.contains("#[inline(always)]"));
+        assert!(!default.contains("#[cold]"));
+        assert!(!default.contains("#[track_caller]"));
+
+        let mut context = RenderContext::new();
+        context.show_perf_attrs = true;
+        let output = render_items(&module, &context);
+        assert!(output.contains("  #[inline(always)]\n  pub fn force_inline()\n"));
+        assert!(output.contains("  #[cold]\n  pub fn error_handler()\n"));
+        assert!(output.contains("  #[track_caller]\n  pub fn expect_positive()\n"));
+        assert!(!output.contains("#[must_use]"));
+    }
+
+    #[test]
+    fn test_function_inputs_accept_array_and_object_shapes() {
+        let crate_data = build_crate(&[], vec![]);
+        let parser = ItemParser::new(&crate_data);
+
+        let (name, ty) = parser.parse_input(&json!(["width", {"primitive": "f64"}])).unwrap();
+        assert_eq!((name.as_str(), ty.to_string().as_str()), ("width", "f64"));
+
+        let (name, ty) = parser
+            .parse_input(&json!({"pattern": "height", "type": {"primitive": "f64"}}))
+            .unwrap();
+        assert_eq!((name.as_str(), ty.to_string().as_str()), ("height", "f64"));
+
+        assert!(parser.parse_input(&json!({"type": {"primitive": "f64"}})).is_none());
+        assert!(parser.parse_input(&json!(["only_a_name"])).is_none());
+    }
+
+    #[test]
+    fn test_stats_count_nested_items_by_kind_and_visibility() {
+        let mut inner = item(1, "inner", json!({"module": {"is_crate": false, "items": [2, 3], "is_stripped": false}}));
+        inner["visibility"] = json!("crate");
+        let mut helper = item(3, "helper", function(json!([]), Value::Null, json!({})));
+        helper["visibility"] = json!("crate");
+        let crate_data = build_crate(
+            &[1, 4],
+            vec![inner, item(2, "Point", plain_struct(&[])), helper, item(4, "Named", marker_trait())],
+        );
+
+        let stats = CrateStats::collect(&parse(&crate_data));
+        assert_eq!(
+            stats.to_string(),
+            "modules: 1, structs: 1, enums: 0, traits: 1, functions: 1, type_aliases: 0, constants: 0, statics: 0, macros: 0 (public: 2, private: 2)"
+        );
+    }
+
+    #[test]
+    fn test_dyn_trait_lifetime_is_never_invented() {
+        let dyn_draw = |lifetime: Value| json!({"dyn_trait": {
+            "traits": [{"trait": {"path": "Draw", "id": 98, "args": null}, "generic_params": []}],
+            "lifetime": lifetime
+        }});
+        let boxed = |inner: Value| json!({"resolved_path": {
+            "path": "Box",
+            "id": 99,
+            "args": {"angle_bracketed": {"args": [{"type": inner}], "constraints": []}}
+        }});
+        // Mirrors `create_circle` from the trait_objects fixture, whose `'static` is elided
+        let crate_data = build_crate(
+            &[1, 2, 3],
+            vec![
+                item(1, "create_circle", function(json!([]), boxed(dyn_draw(Value::Null)), json!({}))),
+                item(2, "create_static", function(json!([]), boxed(dyn_draw(json!("'static"))), json!({}))),
+                item(3, "borrow_static", function(
+                    json!([]),
+                    json!({"borrowed_ref": {"lifetime": null, "is_mutable": false, "type": dyn_draw(json!("'static"))}}),
+                    json!({}),
+                )),
+            ],
+        );
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("  pub fn create_circle() -> Box<dyn Draw>\n"));
+        assert!(output.contains("  pub fn create_static() -> Box<dyn Draw + 'static>\n"));
+        assert!(output.contains("  pub fn borrow_static() -> &(dyn Draw + 'static)\n"));
+    }
+
+    #[test]
+    fn test_where_clause_keeps_lifetime_bounds() {
+        // Mirrors `with_context`'s `E: Error + Send + Sync + 'static` in the advanced_errors fixture
+        let trait_bound = |path: &str| json!({"trait_bound": {
+            "trait": {"path": path, "id": 99, "args": null},
+            "generic_params": [],
+            "modifier": "none"
+        }});
+        let mut with_context = function(json!([]), Value::Null, json!({}));
+        with_context["function"]["generics"] = json!({
+            "params": [
+                {"name": "'a", "kind": {"lifetime": {"outlives": []}}},
+                {"name": "E", "kind": {"type": {"bounds": [], "default": null, "is_synthetic": false}}}
+            ],
+            "where_predicates": [
+                {"bound_predicate": {
+                    "type": {"generic": "E"},
+                    "bounds": [trait_bound("Error"), trait_bound("Send"), trait_bound("Sync"), {"outlives": "'static"}],
+                    "generic_params": []
+                }},
+                {"lifetime_predicate": {"lifetime": "'a", "outlives": ["'static"]}}
+            ]
+        });
+        let crate_data = build_crate(&[1], vec![item(1, "with_context", with_context)]);
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("pub fn with_context<'a, E>() where E: Error + Send + Sync + 'static, 'a: 'static\n"));
+    }
+
+    #[test]
+    fn test_group_by_kind_headers_per_module() {
+        // Shaped like the complex fixture: `Cacheable` sits in `storage` after a struct,
+        // `Protocol` in `network`
+        let storage = item(1, "storage", json!({"module": {"is_crate": false, "items": [3, 2], "is_stripped": false}}));
+        let network = item(4, "network", json!({"module": {"is_crate": false, "items": [5], "is_stripped": false}}));
+        let crate_data = build_crate(
+            &[6, 1, 4],
+            vec![
+                storage,
+                item(2, "Cacheable", marker_trait()),
+                item(3, "Storage", plain_struct(&[])),
+                network,
+                item(5, "Protocol", marker_trait()),
+                item(6, "connect", function(json!([]), Value::Null, json!({}))),
+            ],
+        );
+        let module = parse(&crate_data);
+
+        let mut context = RenderContext::new();
+        context.group_by_kind = true;
+        let output = ParsedRenderer.render(&module, None, &context);
+        assert!(output.contains("  # Modules\n\n  pub mod storage\n"));
+        assert!(output.contains("    # Structs\n\n    pub struct Storage {"));
+        assert!(output.contains("    # Traits\n\n    pub trait Cacheable {"));
+        assert!(output.contains("    # Traits\n\n    pub trait Protocol {"));
+        assert!(output.find("# Modules").unwrap() < output.find("# Functions").unwrap());
+        assert!(output.find("# Structs").unwrap() < output.find("# Traits").unwrap());
+
+        let ungrouped = ParsedRenderer.render(&module, None, &RenderContext::new());
+        assert!(!ungrouped.contains("# Traits"));
+    }
+
+    #[test]
+    fn test_crate_level_attributes_render_as_inner() {
+        // Mirrors the attributes fixture's `#![deny(unsafe_op_in_unsafe_fn)]`
+        let mut crate_data = build_crate(&[], vec![]);
+        let root = crate_data.index.get_mut(&crate_data.root).unwrap();
+        root.attrs = vec!["#[deny(unsafe_op_in_unsafe_fn)]".to_string(), "#![no_std]".to_string()];
+
+        let output = ParsedRenderer.render(&parse(&crate_data), Some("0.1.0"), &RenderContext::new());
+        assert!(output.starts_with(
+            "# Crate: test_crate\n\n#![deny(unsafe_op_in_unsafe_fn)]\n#![no_std]\n\nVersion: 0.1.0\n"
+        ));
+    }
+
+    #[test]
+    fn test_trait_impl_items_render_in_stable_order() {
+        let impl_with_items = |item_order: Value| {
+            let mut handler_impl = auto_trait_impl("Handler", 1, false);
+            handler_impl["impl"]["is_synthetic"] = json!(false);
+            handler_impl["impl"]["items"] = item_order;
+            let output_type = item(5, "Output", json!({"assoc_type": {
+                "generics": empty_generics(),
+                "bounds": [],
+                "type": {"primitive": "u8"}
+            }}));
+            build_crate(
+                &[2],
+                vec![
+                    item(2, "", handler_impl),
+                    item(3, "reset", function(json!([]), Value::Null, json!({}))),
+                    item(4, "handle", function(json!([]), Value::Null, json!({}))),
+                    output_type,
+                    item(6, "close", function(json!([]), Value::Null, json!({}))),
+                ],
+            )
+        };
+
+        let first = render_items(&parse(&impl_with_items(json!([3, 4, 5, 6]))), &RenderContext::new());
+        let second = render_items(&parse(&impl_with_items(json!([6, 5, 4, 3]))), &RenderContext::new());
+        assert_eq!(first, second);
+
+        let positions: Vec<usize> = ["type Output", "fn close", "fn handle", "fn reset"]
+            .iter()
+            .map(|needle| first.find(needle).unwrap())
+            .collect();
+        assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_nested_resolved_path_args_render_fully() {
+        fn path(name: &str, args: Vec<Value>) -> Value {
+            let args: Vec<Value> = args.into_iter().map(|ty| json!({"type": ty})).collect();
+            json!({"resolved_path": {
+                "path": name,
+                "id": 0,
+                "args": {"angle_bracketed": {"args": args, "constraints": []}}
+            }})
+        }
+
+        let boxed = path("Box", vec![json!({"generic": "T"})]);
+        let output_ty = path("Result", vec![path("Option", vec![boxed]), json!({"generic": "E"})]);
+        let crate_data = build_crate(
+            &[1],
+            vec![item(1, "lookup", function(json!([]), output_ty, json!({})))],
+        );
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("  pub fn lookup() -> Result<Option<Box<T>>, E>\n"));
+    }
+
+    #[test]
+    fn test_lifetime_and_const_args_keep_position() {
+        let output_ty = json!({"resolved_path": {
+            "path": "Foo",
+            "id": 0,
+            "args": {"angle_bracketed": {"args": [
+                {"lifetime": "'a"},
+                {"type": {"generic": "T"}},
+                {"const": {"expr": "3", "value": null, "is_literal": true}}
+            ], "constraints": []}}
+        }});
+        let crate_data = build_crate(
+            &[1],
+            vec![item(1, "make", function(json!([]), output_ty, json!({})))],
+        );
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("  pub fn make() -> Foo<'a, T, 3>\n"));
+    }
+
+    #[test]
+    fn test_where_clause_on_concrete_type() {
+        let mut func = function(json!([]), Value::Null, json!({}));
+        func["function"]["generics"]["where_predicates"] = json!([{"bound_predicate": {
+            "type": {"resolved_path": {
+                "path": "Vec",
+                "id": 0,
+                "args": {"angle_bracketed": {"args": [{"type": {"generic": "T"}}], "constraints": []}}
+            }},
+            "bounds": [{"trait_bound": {
+                "trait": {"path": "Debug", "id": 0, "args": null},
+                "generic_params": [],
+                "modifier": "none"
+            }}],
+            "generic_params": []
+        }}]);
+        let crate_data = build_crate(&[1], vec![item(1, "dump", func)]);
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("pub fn dump() where Vec<T>: Debug\n"));
+    }
+
+    #[test]
+    fn test_filter_by_path_keeps_single_item_and_impls() {
+        let mut circle = plain_struct(&[]);
+        circle["struct"]["impls"] = json!([4]);
+        let mut inherent = auto_trait_impl("Unused", 2, false);
+        inherent["impl"]["trait"] = Value::Null;
+        inherent["impl"]["is_synthetic"] = json!(false);
+        inherent["impl"]["items"] = json!([5]);
+
+        let mut crate_data = build_crate(
+            &[1],
+            vec![
+                item(1, "shapes", json!({"module": {"is_crate": false, "items": [2, 3], "is_stripped": false}})),
+                item(2, "Circle", circle),
+                item(3, "Square", plain_struct(&[])),
+                item(4, "", inherent),
+                item(5, "radius", function(json!([]), json!({"primitive": "f64"}), json!({}))),
+            ],
+        );
+
+        let err = crate::filter_by_path(&mut crate_data.clone(), "shapes::Triangle").unwrap_err();
+        assert!(err.to_string().contains("'Triangle'"));
+
+        crate::filter_by_path(&mut crate_data, "shapes::Circle").unwrap();
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("pub struct Circle"));
+        assert!(output.contains("pub fn radius() -> f64"));
+        assert!(!output.contains("Square"));
+    }
+
+    #[test]
+    fn test_mutability_rendering_matrix() {
+        let t = json!({"generic": "T"});
+        let inputs = json!([
+            ["shared", {"borrowed_ref": {"lifetime": null, "is_mutable": false, "type": t}}],
+            ["unique", {"borrowed_ref": {"lifetime": "'a", "is_mutable": true, "type": t}}],
+            ["read", {"raw_pointer": {"is_mutable": false, "type": t}}],
+            ["write", {"raw_pointer": {"is_mutable": true, "type": t}}]
+        ]);
+        let static_item = |is_mutable: bool| {
+            json!({"static": {"type": {"primitive": "u32"}, "is_mutable": is_mutable, "expr": "0", "is_unsafe": false}})
+        };
+        let crate_data = build_crate(
+            &[1, 2, 3],
+            vec![
+                item(1, "access", function(inputs, Value::Null, json!({}))),
+                item(2, "COUNTER", static_item(true)),
+                item(3, "LIMIT", static_item(false)),
+            ],
+        );
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains(
+            "  pub fn access(shared: &T, unique: &'a mut T, read: *const T, write: *mut T)\n"
+        ));
+        assert!(output.contains("  pub static mut COUNTER: u32 = 0\n"));
+        assert!(output.contains("  pub static LIMIT: u32 = 0\n"));
+    }
+
+    #[test]
+    fn test_exclude_prunes_matching_items_and_emptied_modules() {
+        let crate_data = build_crate(
+            &[1, 4, 6, 7],
+            vec![
+                item(1, "utils", json!({"module": {"is_crate": false, "items": [2, 3], "is_stripped": false}})),
+                item(2, "format_helper", function(json!([]), Value::Null, json!({}))),
+                item(3, "format", function(json!([]), Value::Null, json!({}))),
+                item(4, "testing", json!({"module": {"is_crate": false, "items": [5], "is_stripped": false}})),
+                item(5, "setup_helper", function(json!([]), Value::Null, json!({}))),
+                item(6, "__private_api", function(json!([]), Value::Null, json!({}))),
+                item(7, "run", function(json!([]), Value::Null, json!({}))),
+            ],
+        );
+
+        let mut module = parse(&crate_data);
+        exclude_items(&mut module, &["*_helper".to_string(), "__*".to_string()]);
+        let output = render_items(&module, &RenderContext::new());
+        assert!(!output.contains("helper"));
+        assert!(!output.contains("__private_api"));
+        assert!(!output.contains("mod testing"));
+        assert!(output.contains("pub mod utils"));
+        assert!(output.contains("pub fn format()"));
+        assert!(output.contains("pub fn run()"));
+    }
+
+    #[test]
+    fn test_inherited_default_methods_only_with_flag() {
+        let mut is_clickable = item(11, "is_clickable", function(json!([]), json!({"primitive": "bool"}), json!({})));
+        is_clickable["docs"] = json!("Check if the object is clickable");
+        let clickable = json!({"trait": {
+            "is_auto": false,
+            "is_unsafe": false,
+            "is_dyn_compatible": true,
+            "items": [10, 11],
+            "generics": empty_generics(),
+            "bounds": [],
+            "implementations": [4]
+        }});
+
+        let mut circle = plain_struct(&[]);
+        circle["struct"]["impls"] = json!([4]);
+        let mut impl_block = auto_trait_impl("Clickable", 2, false);
+        impl_block["impl"]["trait"]["id"] = json!(1);
+        impl_block["impl"]["for"]["resolved_path"]["path"] = json!("Circle");
+        impl_block["impl"]["is_synthetic"] = json!(false);
+        impl_block["impl"]["items"] = json!([5]);
+        impl_block["impl"]["provided_trait_methods"] = json!(["is_clickable"]);
+
+        let crate_data = build_crate(
+            &[1, 2],
+            vec![
+                item(1, "Clickable", clickable),
+                item(2, "Circle", circle),
+                item(4, "", impl_block),
+                item(5, "on_click", function(json!([]), Value::Null, json!({}))),
+                item(10, "on_click", function(json!([]), Value::Null, json!({}))),
+                is_clickable,
+            ],
+        );
+        let module = parse(&crate_data);
+
+        let output = render_items(&module, &RenderContext::new());
+        assert!(output.contains("impl Clickable for Circle {"));
+        assert!(!output.contains("// Provided by the trait"));
+
+        let mut context = RenderContext::new();
+        context.show_inherited = true;
+        let output = render_items(&module, &context);
+        assert!(output.contains(
+            "    // Provided by the trait\n    /// Check if the object is clickable\n    fn is_clickable() -> bool\n"
+        ));
+    }
+
+    #[test]
+    fn test_doc_aliases_rendered_under_docs() {
+        let mut point = item(1, "Point", plain_struct(&[]));
+        point["docs"] = json!("A 2D point");
+        point["attrs"] = json!(["#[doc(alias = \"Vec2\")]", "#[doc(alias(\"Coord\", \"XY\"))]"]);
+        let crate_data = build_crate(&[1], vec![point]);
+        let module = parse(&crate_data);
+
+        let output = render_items(&module, &RenderContext::new());
+        assert!(output.contains("  /// A 2D point\n  // aliases: Vec2, Coord, XY\n  pub struct Point"));
+
+        let mut context = RenderContext::new();
+        context.no_auto_comment = true;
+        assert!(!render_items(&module, &context).contains("aliases"));
+    }
+
+    #[test]
+    fn test_external_path_field_keeps_generic_args() {
+        let string = json!({"resolved_path": {"path": "String", "id": 98, "args": null}});
+        // The id points into another crate, so there's no index entry behind it
+        let headers = item(2, "headers", json!({"struct_field": {"resolved_path": {
+            "path": "std::collections::HashMap",
+            "id": 97,
+            "args": {"angle_bracketed": {"args": [{"type": string}, {"type": string}], "constraints": []}}
+        }}}));
+        let crate_data = build_crate(&[1], vec![item(1, "HttpRequest", plain_struct(&[2])), headers]);
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("    pub headers: std::collections::HashMap<String, String>\n"));
+    }
+
+    #[test]
+    fn test_cfg_gated_impl_renders_cfg_above_header() {
+        let mut platform = plain_struct(&[]);
+        platform["struct"]["impls"] = json!([4, 5]);
+        let mut gated = auto_trait_impl("Default", 1, false);
+        gated["impl"]["is_synthetic"] = json!(false);
+        gated["impl"]["for"]["resolved_path"]["path"] = json!("Platform");
+        let mut traced = gated.clone();
+        traced["impl"]["trait"]["path"] = json!("Clone");
+
+        let mut gated = item(4, "", gated);
+        gated["attrs"] = json!(["#[cfg(feature = \"advanced\")]"]);
+        // Newer rustdoc only keeps a debug dump of the cfg
+        let mut traced = item(5, "", traced);
+        traced["attrs"] = json!([concat!(
+            "#[attr = CfgTrace([All([NameValue { name: \"unix\", value: None, span: src/lib.rs:2:11: 2:15 (#0) }, ",
+            "Not(NameValue { name: \"target_pointer_width\", value: Some(\"32\"), span: src/lib.rs:2:21: 2:28 (#0) }, ",
+            "src/lib.rs:2:20: 2:29 (#0))], src/lib.rs:2:10: 2:30 (#0))])]"
+        )]);
+        let crate_data = build_crate(&[1], vec![item(1, "Platform", platform), gated, traced]);
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("  #[cfg(feature = \"advanced\")]\n  impl Default for Platform\n"));
+        assert!(output.contains(
+            "  #[cfg(all(unix, not(target_pointer_width = \"32\")))]\n  impl Clone for Platform\n"
+        ));
+    }
+
+    #[test]
+    fn test_items_in_restricted_module_keep_own_visibility() {
+        let module = |items: &[u32]| json!({"module": {"is_crate": false, "items": items, "is_stripped": false}});
+        let mut database = item(1, "database", module(&[2, 4, 5]));
+        database["visibility"] = json!("crate");
+        let mut pool_size = item(4, "POOL_SIZE", json!({"constant": {
+            "type": {"primitive": "usize"},
+            "const": {"expr": "4", "value": null, "is_literal": true}
+        }}));
+        pool_size["visibility"] = json!("crate");
+        let mut connections = item(5, "CONNECTIONS", json!({"static": {
+            "type": {"primitive": "usize"}, "is_mutable": false, "expr": "0", "is_unsafe": false
+        }}));
+        connections["visibility"] = json!("default");
+        let sql = json!({"borrowed_ref": {"lifetime": null, "is_mutable": false, "type": {"primitive": "str"}}});
+
+        let crate_data = build_crate(
+            &[1],
+            vec![
+                database,
+                item(2, "ops", module(&[3])),
+                item(3, "query", function(json!([["sql", sql]]), Value::Null, json!({}))),
+                pool_size,
+                connections,
+            ],
+        );
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("  pub(crate) mod database\n"));
+        assert!(output.contains("    pub mod ops\n"));
+        assert!(output.contains("      pub fn query(sql: &str)\n"));
+        assert!(output.contains("    pub(crate) const POOL_SIZE: usize = 4\n"));
+        assert!(output.contains("    static CONNECTIONS: usize = 0\n"));
+    }
+
+    #[test]
+    fn test_tuple_variant_renders_every_field() {
+        let field = |id: u32, name: &str| {
+            let mut field = item(id, name, json!({"struct_field": {"primitive": "u8"}}));
+            field["visibility"] = json!("default");
+            field
+        };
+        let color = json!({"enum": {
+            "generics": empty_generics(),
+            "has_stripped_variants": false,
+            "variants": [2, 3],
+            "impls": []
+        }});
+        let crate_data = build_crate(
+            &[1],
+            vec![
+                item(1, "Color", color),
+                item(2, "Red", json!({"variant": {"kind": "plain", "discriminant": null}})),
+                item(3, "Rgb", json!({"variant": {"kind": {"tuple": [4, 5, 6]}, "discriminant": null}})),
+                field(4, "0"),
+                field(5, "1"),
+                field(6, "2"),
+            ],
+        );
+
+        let module = parse(&crate_data);
+        let ParsedItem::Enum(parsed) = &module.items[0] else {
+            panic!("expected an enum");
+        };
+        let VariantKind::Tuple(fields) = &parsed.variants[1].kind else {
+            panic!("expected a tuple variant");
+        };
+        assert_eq!(fields.len(), 3);
+        assert!(fields.iter().all(|f| f.visibility == Visibility::Default));
+
+        let output = render_items(&module, &RenderContext::new());
+        assert!(output.contains("    Rgb(u8, u8, u8)\n"));
+    }
+
+    #[test]
+    fn test_inlined_reexport_notes_its_source() {
+        let mut shapes = item(1, "shapes", json!({"module": {"is_crate": false, "items": [2], "is_stripped": false}}));
+        shapes["visibility"] = json!("crate");
+        let reexport = |id: u32, name: &str| {
+            item(id, name, json!({"use": {"source": "shapes::Circle", "name": name, "id": 2, "is_glob": false}}))
+        };
+        let crate_data = build_crate(
+            &[1, 3, 4],
+            vec![shapes, item(2, "Circle", plain_struct(&[])), reexport(3, "Circle"), reexport(4, "Round")],
+        );
+
+        // Without the flag re-exports are left out, as before
+        assert_eq!(parse(&crate_data).items.len(), 1);
+
+        let module = ItemParser::new(&crate_data)
+            .with_inline_reexports(true)
+            .parse_crate()
+            .expect("crate should parse");
+        let output = render_items(&module, &RenderContext::new());
+        assert!(output.contains("  // re-exported from shapes::Circle\n  pub struct Circle {\n"));
+        assert!(output.contains("  // re-exported from shapes::Circle as Round\n  pub struct Circle {\n"));
+
+        let mut context = RenderContext::new();
+        context.no_auto_comment = true;
+        assert!(!render_items(&module, &context).contains("re-exported"));
+    }
+
+    #[test]
+    fn test_equality_where_predicate_renders_projection() {
+        let item_of = |self_type: Value| json!({"qualified_path": {
+            "name": "Item",
+            "args": null,
+            "self_type": self_type,
+            "trait": {"path": "Iterator", "id": 90, "args": null}
+        }});
+        let mut sum = function(json!([["iter", {"generic": "I"}]]), json!({"primitive": "u32"}), json!({}));
+        sum["function"]["generics"] = json!({
+            "params": [{"name": "I", "kind": {"type": {
+                "bounds": [], "default": null, "is_synthetic": false
+            }}}],
+            "where_predicates": [
+                {"eq_predicate": {"lhs": item_of(json!({"generic": "I"})), "rhs": {"type": {"primitive": "u32"}}}},
+                {"bound_predicate": {
+                    "type": item_of(json!({"generic": "I"})),
+                    "bounds": [{"trait_bound": {
+                        "trait": {"path": "Copy", "id": 91, "args": null},
+                        "generic_params": [],
+                        "modifier": "none"
+                    }}],
+                    "generic_params": []
+                }}
+            ]
+        });
+        let crate_data = build_crate(&[1], vec![item(1, "sum", sum)]);
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("pub fn sum<I>(iter: I) -> u32 where <I as Iterator>::Item = u32, I::Item: Copy\n"));
+    }
+
+    #[test]
+    fn test_deprecated_variant_notice_precedes_docs() {
+        let status = json!({"enum": {
+            "generics": empty_generics(),
+            "has_stripped_variants": false,
+            "variants": [2, 3],
+            "impls": []
+        }});
+        let mut teapot = item(2, "Teapot", json!({"variant": {"kind": "plain", "discriminant": null}}));
+        teapot["docs"] = json!("418 I'm a teapot");
+        teapot["deprecation"] = json!({"since": "1.4.0", "note": "Not a real status"});
+        let mut other = item(3, "Other", json!({"variant": {"kind": "plain", "discriminant": null}}));
+        other["attrs"] = json!(["#[non_exhaustive]"]);
+        let crate_data = build_crate(&[1], vec![item(1, "HttpStatus", status), teapot, other]);
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("    DEPRECATED since 1.4.0\n    /// 418 I'm a teapot\n    Teapot\n"));
+        assert!(output.contains("    #[non_exhaustive]\n    Other\n"));
+    }
+
+    #[test]
+    fn test_signatures_only_drops_every_doc_comment() {
+        let mut docs = item(1, "docs", json!({"module": {"is_crate": false, "items": [2, 3], "is_stripped": false}}));
+        docs["docs"] = json!("Module docs");
+        let mut legacy = item(2, "legacy", function(json!([]), Value::Null, json!({})));
+        legacy["docs"] = json!("Old entry point");
+        legacy["deprecation"] = json!({"since": "0.2.0", "note": null});
+        let mut config = item(3, "Config", plain_struct(&[]));
+        config["docs"] = json!("Settings\n\n# Examples\n\n```\nConfig\n```");
+        let crate_data = build_crate(&[1], vec![docs, legacy, config]);
+
+        let mut context = RenderContext::new();
+        context.signatures_only = true;
+        let output = render_items(&parse(&crate_data), &context);
+        assert!(!output.contains("///") && !output.contains("//!"));
+        assert!(output.contains("    DEPRECATED since 0.2.0\n    pub fn legacy()\n"));
+        assert!(output.contains("    pub struct Config {\n"));
+    }
+
+    #[test]
+    fn test_impl_headers_keep_inherent_impl_bounds() {
+        let bound = |path: &str| json!({"trait_bound": {
+            "trait": {"path": path, "id": 90, "args": null},
+            "generic_params": [],
+            "modifier": "none"
+        }});
+        let param = |name: &str| json!({"name": name, "kind": {"type": {
+            "bounds": [], "default": null, "is_synthetic": false
+        }}});
+        let generics = json!({
+            "params": [param("K"), param("V")],
+            "where_predicates": [
+                {"bound_predicate": {"type": {"generic": "K"}, "bounds": [bound("Clone"), bound("Hash")], "generic_params": []}},
+                {"bound_predicate": {"type": {"generic": "V"}, "bounds": [bound("Clone")], "generic_params": []}}
+            ]
+        });
+        let generic_arg = |name: &str| json!({"type": {"generic": name}});
+        let mut storage = plain_struct(&[]);
+        storage["struct"]["impls"] = json!([2]);
+        let mut inherent = auto_trait_impl("", 1, false);
+        inherent["impl"]["trait"] = Value::Null;
+        inherent["impl"]["is_synthetic"] = json!(false);
+        inherent["impl"]["generics"] = generics;
+        inherent["impl"]["items"] = json!([3]);
+        inherent["impl"]["for"] = json!({"resolved_path": {
+            "path": "Storage",
+            "id": 1,
+            "args": {"angle_bracketed": {"args": [generic_arg("K"), generic_arg("V")], "constraints": []}}
+        }});
+        let len = function(json!([["self", {"borrowed_ref": {
+            "lifetime": null, "is_mutable": false, "type": {"generic": "Self"}
+        }}]]), json!({"primitive": "usize"}), json!({}));
+        let crate_data = build_crate(
+            &[1],
+            vec![item(1, "Storage", storage), item(2, "", inherent), item(3, "len", len)],
+        );
+        let module = parse(&crate_data);
+
+        // By default the methods are flattened into the struct body
+        let output = render_items(&module, &RenderContext::new());
+        assert!(output.contains("  pub struct Storage {\n\n    pub fn len(&self) -> usize\n  }\n"));
+        assert!(!output.contains("impl<"));
+
+        let mut context = RenderContext::new();
+        context.show_impl_headers = true;
+        let output = render_items(&module, &context);
+        assert!(output.contains("  pub struct Storage {\n  }\n"));
+        assert!(output.contains(
+            "  impl<K, V> Storage<K, V> where K: Clone + Hash, V: Clone {\n    pub fn len(&self) -> usize\n  }\n"
+        ));
+    }
+
+    #[test]
+    fn test_async_trait_method_keeps_surface_form() {
+        let self_ref = json!({"borrowed_ref": {"lifetime": null, "is_mutable": false, "type": {"generic": "Self"}}});
+        let string = json!({"resolved_path": {"path": "String", "id": 98, "args": null}});
+        let mut async_trait = marker_trait();
+        async_trait["trait"]["items"] = json!([2, 3]);
+        let async_method = function(json!([["self", self_ref]]), string.clone(), json!({"is_async": true}));
+        let sync_method = function(json!([["self", self_ref]]), string, json!({}));
+        let crate_data = build_crate(
+            &[1],
+            vec![
+                item(1, "AsyncTrait", async_trait),
+                item(2, "async_method", async_method),
+                item(3, "name", sync_method),
+            ],
+        );
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("    async fn async_method(&self) -> String\n"));
+        assert!(output.contains("    fn name(&self) -> String\n"));
+    }
+
+    #[test]
+    fn test_impl_fn_parameter_renders_closure_signature() {
+        let fn_bound = json!({"trait_bound": {
+            "trait": {"path": "Fn", "id": 90, "args": {"parenthesized": {
+                "inputs": [{"primitive": "i32"}],
+                "output": {"primitive": "i32"}
+            }}},
+            "generic_params": [],
+            "modifier": "none"
+        }});
+        let mut apply = function(
+            json!([["f", {"impl_trait": [fn_bound]}], ["x", {"primitive": "i32"}]]),
+            json!({"primitive": "i32"}),
+            json!({}),
+        );
+        // rustdoc also lists the `impl Trait` argument as a synthetic generic parameter
+        apply["function"]["generics"]["params"] = json!([{"name": "impl Fn(i32) -> i32", "kind": {"type": {
+            "bounds": [fn_bound], "default": null, "is_synthetic": true
+        }}}]);
+        let crate_data = build_crate(&[1], vec![item(1, "apply_twice", apply)]);
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("  pub fn apply_twice(f: impl Fn(i32) -> i32, x: i32) -> i32\n"));
+    }
+
+    #[test]
+    fn test_user_written_drop_impl_is_kept() {
+        let mut guard = plain_struct(&[]);
+        guard["struct"]["impls"] = json!([2, 4]);
+        let mut drop_impl = auto_trait_impl("Drop", 1, false);
+        drop_impl["impl"]["is_synthetic"] = json!(false);
+        drop_impl["impl"]["items"] = json!([3]);
+        drop_impl["impl"]["for"]["resolved_path"]["path"] = json!("CriticalSectionGuard");
+        let self_mut = json!({"borrowed_ref": {"lifetime": null, "is_mutable": true, "type": {"generic": "Self"}}});
+        let drop_fn = function(json!([["self", self_mut]]), Value::Null, json!({}));
+        // Auto traits next to it are still filtered
+        let mut send = auto_trait_impl("Send", 1, false);
+        send["impl"]["for"]["resolved_path"]["path"] = json!("CriticalSectionGuard");
+        let crate_data = build_crate(
+            &[1],
+            vec![
+                item(1, "CriticalSectionGuard", guard),
+                item(2, "", drop_impl),
+                item(3, "drop", drop_fn),
+                item(4, "", send),
+            ],
+        );
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("  impl Drop for CriticalSectionGuard {\n\n    fn drop(&mut self)\n  }\n"));
+        assert!(!output.contains("impl Send"));
+    }
+
+    #[test]
+    fn test_external_types_qualified_by_origin_crate() {
+        let map = item(2, "cache", json!({"struct_field": {"resolved_path": {"path": "HashMap", "id": 97, "args": null}}}));
+        // A local type from the index is left alone
+        let local = item(3, "owner", json!({"struct_field": {"resolved_path": {"path": "Owner", "id": 4, "args": null}}}));
+        let mut crate_data = build_crate(
+            &[1, 4],
+            vec![item(1, "Registry", plain_struct(&[2, 3])), map, local, item(4, "Owner", plain_struct(&[]))],
+        );
+        crate_data.paths = serde_json::from_value(json!({"97": {
+            "crate_id": 1, "path": ["std", "collections", "hash", "map", "HashMap"], "kind": "struct"
+        }}))
+        .unwrap();
+        crate_data.external_crates = serde_json::from_value(json!({"1": {"name": "std", "html_root_url": null}})).unwrap();
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("    pub cache: HashMap\n"));
+
+        let module = ItemParser::new(&crate_data)
+            .with_qualified_external_paths(true)
+            .parse_crate()
+            .expect("crate should parse");
+        let output = render_items(&module, &RenderContext::new());
+        assert!(output.contains("    pub cache: std::collections::hash::map::HashMap\n"));
+        assert!(output.contains("    pub owner: Owner\n"));
+    }
+
+    #[test]
+    fn test_trait_lifetime_parameter_renders_on_declaration_and_use() {
+        // Mirrors `Borrowing<'a>` and `use_borrowing` from the trait_objects fixture
+        let mut borrowing = marker_trait();
+        borrowing["trait"]["generics"]["params"] = json!([{"name": "'a", "kind": {"lifetime": {"outlives": []}}}]);
+        let dyn_borrowing = json!({"dyn_trait": {
+            "traits": [{
+                "trait": {
+                    "path": "Borrowing",
+                    "id": 1,
+                    "args": {"angle_bracketed": {"args": [{"lifetime": "'a"}], "constraints": []}}
+                },
+                "generic_params": []
+            }],
+            "lifetime": null
+        }});
+        let mut use_borrowing = function(
+            json!([["borrower", {"borrowed_ref": {"lifetime": null, "is_mutable": false, "type": dyn_borrowing}}]]),
+            json!({"borrowed_ref": {"lifetime": "'a", "is_mutable": false, "type": {"primitive": "str"}}}),
+            json!({}),
+        );
+        use_borrowing["function"]["generics"]["params"] = json!([{"name": "'a", "kind": {"lifetime": {"outlives": []}}}]);
+        let crate_data = build_crate(&[1, 2], vec![item(1, "Borrowing", borrowing), item(2, "use_borrowing", use_borrowing)]);
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("pub trait Borrowing<'a> {"));
+        assert!(output.contains("pub fn use_borrowing<'a>(borrower: &dyn Borrowing<'a>) -> &'a str\n"));
+    }
+
+    #[test]
+    fn test_macro_keeps_every_arm_and_matcher_delimiter() {
+        let source = "macro_rules! make_map {\n    {} => { ... };\n    {$($key:expr => $value:expr),+ $(,)?} => { ... };\n    [$crate::Marker; $n:literal] => { ... };\n}";
+        let crate_data = build_crate(&[1], vec![item(1, "make_map", json!({"macro": source}))]);
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert_eq!(
+            output,
+            "  macro_rules! make_map {\n      {} => { ... };\n      {$($key:expr => $value:expr),+ $(,)?} => { ... };\n      [test_crate::Marker; $n:literal] => { ... };\n  }\n\n"
+        );
+    }
+
+    #[test]
+    fn test_breadth_first_renders_items_before_submodules() {
+        // Shaped like `utils` in the modules fixture, where `trim` follows `nested`
+        let utils = item(1, "utils", json!({"module": {"is_crate": false, "items": [2, 3, 5], "is_stripped": false}}));
+        let nested = item(3, "nested", json!({"module": {"is_crate": false, "items": [4], "is_stripped": false}}));
+        let crate_data = build_crate(
+            &[1],
+            vec![
+                utils,
+                item(2, "helper", function(json!([]), Value::Null, json!({}))),
+                nested,
+                item(4, "deep_function", function(json!([]), Value::Null, json!({}))),
+                item(5, "trim", function(json!([]), Value::Null, json!({}))),
+            ],
+        );
+        let module = parse(&crate_data);
+        let position = |output: &str, needle: &str| output.find(needle).expect(needle);
+
+        let source_order = render_items(&module, &RenderContext::new());
+        assert!(position(&source_order, "pub mod nested") < position(&source_order, "pub fn trim()"));
+
+        let mut context = RenderContext::new();
+        context.breadth_first = true;
+        let breadth_first = render_items(&module, &context);
+        assert!(position(&breadth_first, "pub fn trim()") < position(&breadth_first, "pub mod nested"));
+        assert!(position(&breadth_first, "pub fn helper()") < position(&breadth_first, "pub fn trim()"));
+    }
+
+    #[test]
+    fn test_const_generic_function_renders_param_and_array_return() {
+        // Mirrors `make` from the generics fixture
+        let mut make = function(
+            json!([]),
+            json!({"array": {"type": {"primitive": "u8"}, "len": "N"}}),
+            json!({}),
+        );
+        make["function"]["generics"]["params"] = json!([
+            {"name": "N", "kind": {"const": {"type": {"primitive": "usize"}, "default": null}}}
+        ]);
+        let crate_data = build_crate(&[1], vec![item(1, "make", make)]);
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("  pub fn make<const N: usize>() -> [u8; N]\n"));
+    }
+
+    #[test]
+    fn test_sort_impls_orders_trait_impls_by_name() {
+        let user_impl = |trait_path: &str| {
+            let mut impl_ = auto_trait_impl(trait_path, 1, false);
+            impl_["impl"]["is_synthetic"] = json!(false);
+            impl_
+        };
+        let mut handle = plain_struct(&[]);
+        handle["struct"]["impls"] = json!([2, 3, 4]);
+        let crate_data = build_crate(
+            &[1],
+            vec![
+                item(1, "Handle", handle),
+                item(2, "", user_impl("std::fmt::Display")),
+                item(3, "", user_impl("Clone")),
+                item(4, "", user_impl("fmt::Debug")),
+            ],
+        );
+        let module = parse(&crate_data);
+        let impl_lines = |output: String| -> Vec<String> {
+            output.lines().filter(|line| line.trim_start().starts_with("impl ")).map(str::to_string).collect()
+        };
+
+        let index_order = impl_lines(render_items(&module, &RenderContext::new()));
+        assert_eq!(
+            index_order,
+            ["  impl std::fmt::Display for Handle", "  impl Clone for Handle", "  impl fmt::Debug for Handle"]
+        );
+
+        let mut context = RenderContext::new();
+        context.sort_impls = true;
+        let sorted = impl_lines(render_items(&module, &context));
+        assert_eq!(
+            sorted,
+            ["  impl Clone for Handle", "  impl fmt::Debug for Handle", "  impl std::fmt::Display for Handle"]
+        );
+    }
+
+    #[test]
+    fn test_trait_default_generic_param_renders() {
+        // Mirrors `Combine<Rhs = Self>` from the generics fixture
+        let mut combine = marker_trait();
+        combine["trait"]["generics"]["params"] = json!([{
+            "name": "Rhs",
+            "kind": {"type": {"bounds": [], "default": {"generic": "Self"}, "is_synthetic": false}}
+        }]);
+        let crate_data = build_crate(&[1], vec![item(1, "Combine", combine)]);
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("  pub trait Combine<Rhs = Self> {"));
+    }
+
+    #[test]
+    fn test_maybe_sized_bound_keeps_question_mark() {
+        // Mirrors `size_of_ref<T: ?Sized>` from the generics fixture, plus a where-clause form
+        let sized = json!({"trait_bound": {
+            "trait": {"path": "Sized", "id": 99, "args": null},
+            "generic_params": [],
+            "modifier": "maybe"
+        }});
+        let mut size_of_ref = function(
+            json!([["value", {"borrowed_ref": {"lifetime": null, "is_mutable": false, "type": {"generic": "T"}}}]]),
+            json!({"primitive": "usize"}),
+            json!({}),
+        );
+        size_of_ref["function"]["generics"] = json!({
+            "params": [
+                {"name": "T", "kind": {"type": {"bounds": [sized], "default": null, "is_synthetic": false}}},
+                {"name": "U", "kind": {"type": {"bounds": [], "default": null, "is_synthetic": false}}}
+            ],
+            "where_predicates": [{"bound_predicate": {
+                "type": {"generic": "U"},
+                "bounds": [sized],
+                "generic_params": []
+            }}]
+        });
+        let crate_data = build_crate(&[1], vec![item(1, "size_of_ref", size_of_ref)]);
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("pub fn size_of_ref<T: ?Sized, U>(value: &T) -> usize where U: ?Sized\n"));
+    }
+
+    #[test]
+    fn test_markdown_table_in_docs_keeps_alignment() {
+        let table = "| Level | Meaning      |\n|-------|--------------|\n| 0     | off          |\n| 1     | errors only  |";
+        let mut log_level = item(1, "LogLevel", plain_struct(&[]));
+        log_level["docs"] = json!(format!("Verbosity levels:\n\n{}\n\nDefaults to 1.", table));
+        let crate_data = build_crate(&[1], vec![log_level]);
+        let module = parse(&crate_data);
+
+        let text = render_items(&module, &RenderContext::new());
+        let prefixed: Vec<String> = table.lines().map(|row| format!("  /// {}", row)).collect();
+        assert!(text.contains(&prefixed.join("\n")));
+
+        let man = ManRenderer.render(&module, None, &RenderContext::new());
+        assert!(man.contains(".nf\n| Level | Meaning      |\n|\\-\\-\\-\\-\\-\\-\\-|\\-\\-\\-\\-\\-\\-\\-\\-\\-\\-\\-\\-\\-\\-|\n"));
+        assert!(man.contains("| 1     | errors only  |\n.fi\n"));
+        assert!(man.contains("Verbosity levels:\n.IP\n.nf\n"));
+    }
+
+    #[test]
+    fn test_overridden_default_method_is_not_inherited() {
+        // Mirrors `impl Draw for Circle` from the trait_objects fixture, which
+        // overrides both the required `draw` and the defaulted `name`
+        let mut required_draw = item(10, "draw", function(json!([]), json!({"primitive": "str"}), json!({})));
+        required_draw["inner"]["function"]["has_body"] = json!(false);
+        let draw = json!({"trait": {
+            "is_auto": false,
+            "is_unsafe": false,
+            "is_dyn_compatible": true,
+            "items": [10, 11],
+            "generics": empty_generics(),
+            "bounds": [],
+            "implementations": [4]
+        }});
+
+        let mut circle = plain_struct(&[]);
+        circle["struct"]["impls"] = json!([4]);
+        let mut impl_block = auto_trait_impl("Draw", 2, false);
+        impl_block["impl"]["trait"]["id"] = json!(1);
+        impl_block["impl"]["for"]["resolved_path"]["path"] = json!("Circle");
+        impl_block["impl"]["is_synthetic"] = json!(false);
+        impl_block["impl"]["items"] = json!([5, 6]);
+        impl_block["impl"]["provided_trait_methods"] = json!(["name"]);
+
+        let crate_data = build_crate(
+            &[1, 2],
+            vec![
+                item(1, "Draw", draw),
+                item(2, "Circle", circle),
+                item(4, "", impl_block),
+                item(5, "draw", function(json!([]), json!({"primitive": "str"}), json!({}))),
+                item(6, "name", function(json!([]), json!({"primitive": "str"}), json!({}))),
+                required_draw,
+                item(11, "name", function(json!([]), json!({"primitive": "str"}), json!({}))),
+            ],
+        );
+
+        let mut context = RenderContext::new();
+        context.show_inherited = true;
+        let output = render_items(&parse(&crate_data), &context);
+        assert!(output.contains("  impl Draw for Circle {\n\n    fn draw() -> str\n\n    fn name() -> str\n  }\n"));
+        assert!(!output.contains("// Provided by the trait"));
+    }
+
+    #[test]
+    fn test_inherent_impl_assoc_const_renders_with_value() {
+        let mut limits = plain_struct(&[]);
+        limits["struct"]["impls"] = json!([2]);
+        let mut impl_block = auto_trait_impl("", 1, false);
+        impl_block["impl"]["trait"] = Value::Null;
+        impl_block["impl"]["for"]["resolved_path"]["path"] = json!("Limits");
+        impl_block["impl"]["is_synthetic"] = json!(false);
+        impl_block["impl"]["items"] = json!([3]);
+        let mut max = item(3, "MAX", json!({"assoc_const": {"type": {"primitive": "usize"}, "value": "10"}}));
+        max["docs"] = json!("Largest accepted size");
+        let crate_data = build_crate(&[1], vec![item(1, "Limits", limits), item(2, "", impl_block), max]);
+        let module = parse(&crate_data);
+
+        let output = render_items(&module, &RenderContext::new());
+        assert!(output.contains("  pub struct Limits {\n\n    /// Largest accepted size\n    pub const MAX: usize = 10\n  }\n"));
+
+        let mut context = RenderContext::new();
+        context.show_impl_headers = true;
+        let output = render_items(&module, &context);
+        assert!(output.contains("  impl Limits {\n    /// Largest accepted size\n    pub const MAX: usize = 10\n  }\n"));
+    }
+
+    #[test]
+    fn test_assoc_types_render_in_trait_and_inherent_impls() {
+        let impl_of = |trait_path: Option<&str>, items: Value| {
+            let mut impl_block = auto_trait_impl(trait_path.unwrap_or(""), 1, false);
+            if trait_path.is_none() {
+                impl_block["impl"]["trait"] = Value::Null;
+            }
+            impl_block["impl"]["for"]["resolved_path"]["path"] = json!("Counter");
+            impl_block["impl"]["is_synthetic"] = json!(false);
+            impl_block["impl"]["items"] = items;
+            impl_block
+        };
+        let assoc_type = |id: u32, name: &str| {
+            item(id, name, json!({"assoc_type": {
+                "generics": empty_generics(),
+                "bounds": [],
+                "type": {"primitive": "u32"}
+            }}))
+        };
+        let mut counter = plain_struct(&[]);
+        counter["struct"]["impls"] = json!([2, 3]);
+        let crate_data = build_crate(
+            &[1],
+            vec![
+                item(1, "Counter", counter),
+                item(2, "", impl_of(None, json!([4]))),
+                item(3, "", impl_of(Some("Iterator"), json!([5]))),
+                assoc_type(4, "Step"),
+                assoc_type(5, "Item"),
+            ],
+        );
+        let module = parse(&crate_data);
+
+        let output = render_items(&module, &RenderContext::new());
+        assert!(output.contains("  pub struct Counter {\n\n    pub type Step = u32\n  }\n"));
+        assert!(output.contains("  impl Iterator for Counter {\n\n    type Item = u32\n  }\n"));
+
+        let mut context = RenderContext::new();
+        context.show_impl_headers = true;
+        assert!(render_items(&module, &context).contains("  impl Counter {\n    pub type Step = u32\n  }\n"));
+    }
+
+    #[test]
+    fn test_max_items_truncates_crate_root_with_note() {
+        let crate_data = build_crate(
+            &[1, 2, 3, 4],
+            vec![
+                item(1, "first", function(json!([]), Value::Null, json!({}))),
+                item(2, "second", function(json!([]), Value::Null, json!({}))),
+                item(3, "third", function(json!([]), Value::Null, json!({}))),
+                item(4, "fourth", function(json!([]), Value::Null, json!({}))),
+            ],
+        );
+        let module = parse(&crate_data);
+
+        let mut context = RenderContext::new();
+        context.max_items = Some(2);
+        let output = ParsedRenderer.render(&module, None, &context);
+        assert!(output.contains("pub fn first()") && output.contains("pub fn second()"));
+        assert!(!output.contains("third") && !output.contains("fourth"));
+        assert!(output.ends_with("  // ... 2 more items (pass an item path or use --exclude to narrow)\n"));
+
+        context.no_auto_comment = true;
+        let bare = ParsedRenderer.render(&module, None, &context);
+        assert!(bare.contains("pub fn second()") && !bare.contains("third"));
+        assert!(!bare.contains("more item"));
+
+        context.no_auto_comment = false;
+        context.max_items = Some(4);
+        assert!(!ParsedRenderer.render(&module, None, &context).contains("more item"));
+    }
+
+    #[test]
+    fn test_metadata_lines_follow_version() {
+        let module = parse(&build_crate(&[], vec![]));
+        let mut context = RenderContext::new();
+        context.metadata = Some(crate::renderer::CrateMetadata {
+            license: Some("MIT".to_string()),
+            repository: None,
+            authors: vec!["Ada".to_string(), "Grace".to_string()],
+        });
+        let output = ParsedRenderer.render(&module, Some("0.1.0"), &context);
+        assert!(output.starts_with("# Crate: test_crate\n\nVersion: 0.1.0\nLicense: MIT\nAuthors: Ada, Grace\n\n"));
+
+        let plain = ParsedRenderer.render(&module, Some("0.1.0"), &RenderContext::new());
+        assert!(plain.starts_with("# Crate: test_crate\n\nVersion: 0.1.0\n\n"));
+    }
+
+    #[test]
+    fn test_each_reexport_keeps_its_own_docs() {
+        let mut shapes = item(1, "shapes", json!({"module": {"is_crate": false, "items": [2, 3], "is_stripped": false}}));
+        shapes["visibility"] = json!("crate");
+        let reexport = |id: u32, name: &str, target: u32, docs: &str| {
+            let mut reexport = item(id, name, json!({"use": {
+                "source": format!("shapes::{}", name),
+                "name": name,
+                "id": target,
+                "is_glob": false
+            }}));
+            reexport["docs"] = json!(docs);
+            reexport
+        };
+        let crate_data = build_crate(
+            &[1, 4, 5],
+            vec![
+                shapes,
+                item(2, "Circle", plain_struct(&[])),
+                item(3, "Square", plain_struct(&[])),
+                reexport(4, "Circle", 2, "The round one"),
+                reexport(5, "Square", 3, "The one with corners"),
+            ],
+        );
+
+        let module = ItemParser::new(&crate_data)
+            .with_inline_reexports(true)
+            .parse_crate()
+            .expect("crate should parse");
+        let output = render_items(&module, &RenderContext::new());
+        assert!(output.contains(
+            "  /// The round one\n  // re-exported from shapes::Circle\n  pub struct Circle {\n"
+        ));
+        assert!(output.contains(
+            "  /// The one with corners\n  // re-exported from shapes::Square\n  pub struct Square {\n"
+        ));
+        assert_eq!(output.matches("/// The round one").count(), 1);
+    }
+
+    #[test]
+    fn test_derived_impl_keeps_its_bounds() {
+        // Shaped like `#[derive(Clone, Copy)] struct Handle<T: Copy>`: the derive repeats `T: Copy`
+        let bound = |path: &str| json!({"trait_bound": {
+            "trait": {"path": path, "id": 91, "args": null},
+            "generic_params": [],
+            "modifier": "none"
+        }});
+        let generic_handle = |mut impl_item: Value| {
+            impl_item["impl"]["is_synthetic"] = json!(false);
+            impl_item["impl"]["for"]["resolved_path"]["args"] =
+                json!({"angle_bracketed": {"args": [{"type": {"generic": "T"}}], "constraints": []}});
+            impl_item
+        };
+        let mut clone_impl = generic_handle(auto_trait_impl("Clone", 1, false));
+        clone_impl["impl"]["generics"]["params"] = json!([{"name": "T", "kind": {"type": {
+            "bounds": [bound("$crate::clone::Clone"), bound("Copy"), bound("Copy")],
+            "default": null,
+            "is_synthetic": false
+        }}}]);
+        let mut debug_impl = generic_handle(auto_trait_impl("Debug", 1, false));
+        debug_impl["impl"]["generics"] = json!({
+            "params": [{"name": "T", "kind": {"type": {"bounds": [], "default": null, "is_synthetic": false}}}],
+            "where_predicates": [{"bound_predicate": {
+                "type": {"generic": "T"},
+                "bounds": [bound("$crate::fmt::Debug")],
+                "generic_params": []
+            }}]
+        });
+        let mut handle = plain_struct(&[]);
+        handle["struct"]["impls"] = json!([2, 3]);
+        let crate_data = build_crate(
+            &[1],
+            vec![item(1, "Handle", handle), item(2, "", clone_impl), item(3, "", debug_impl)],
+        );
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("  impl<T: Clone + Copy> Clone for Handle<T>\n"));
+        assert!(output.contains("  impl<T> Debug for Handle<T> where T: std::fmt::Debug\n"));
+    }
+
+    #[test]
+    fn test_primitive_module_paths_render_bare() {
+        let primitive = |path: &str| json!({"resolved_path": {"path": path, "id": 80, "args": null}});
+        let crate_data = build_crate(
+            &[1],
+            vec![item(
+                1,
+                "checksum",
+                function(
+                    json!([["len", primitive("std::primitive::usize")], ["seed", primitive("core::primitive::u32")]]),
+                    primitive("$crate::primitive::u64"),
+                    json!({}),
+                ),
+            )],
+        );
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("pub fn checksum(len: usize, seed: u32) -> u64\n"));
+    }
+
+    #[test]
+    fn test_bounds_spaced_alike_in_generics_and_where_clauses() {
+        let bounds = json!([
+            {"trait_bound": {"trait": {"path": "Clone", "id": 91, "args": null}, "generic_params": [], "modifier": "none"}},
+            {"trait_bound": {"trait": {"path": "Debug", "id": 92, "args": null}, "generic_params": [], "modifier": "none"}}
+        ]);
+        let mut func = function(json!([]), Value::Null, json!({}));
+        func["function"]["generics"] = json!({
+            "params": [{"name": "T", "kind": {"type": {"bounds": bounds, "default": null, "is_synthetic": false}}}],
+            "where_predicates": [{"bound_predicate": {"type": {"generic": "T"}, "bounds": bounds, "generic_params": []}}]
+        });
+        let crate_data = build_crate(&[1], vec![item(1, "inspect", func)]);
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        let in_generics = output.split_once('<').unwrap().1.split_once('>').unwrap().0;
+        let in_where = output.split_once(" where ").unwrap().1.trim_end();
+        assert_eq!(in_generics, "T: Clone + Debug");
+        assert_eq!(in_generics, in_where);
+    }
+
+    #[test]
+    fn test_dyn_trait_inside_shared_pointers() {
+        let poly_trait = |path: &str| json!({"trait": {"path": path, "id": 93, "args": null}, "generic_params": []});
+        let pointer = |path: &str, traits: Value| json!({"resolved_path": {
+            "path": path,
+            "id": 94,
+            "args": {"angle_bracketed": {"args": [{"type": {"dyn_trait": {"traits": traits, "lifetime": null}}}], "constraints": []}}
+        }});
+        let crate_data = build_crate(
+            &[1],
+            vec![item(
+                1,
+                "share",
+                function(
+                    json!([["shape", pointer("Rc", json!([poly_trait("Draw")]))]]),
+                    pointer("Arc", json!([poly_trait("Draw"), poly_trait("Send")])),
+                    json!({}),
+                ),
+            )],
+        );
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("pub fn share(shape: Rc<dyn Draw>) -> Arc<dyn Draw + Send>\n"));
+    }
+
+    #[test]
+    fn test_no_crate_header_keeps_items() {
+        let mut crate_data = build_crate(&[1], vec![item(1, "Config", plain_struct(&[]))]);
+        crate_data.index.get_mut(&crate_data.root).unwrap().attrs = vec!["#[deny(missing_docs)]".to_string()];
+        let module = parse(&crate_data);
+        assert!(ParsedRenderer.render(&module, None, &RenderContext::new()).contains("#![deny(missing_docs)]"));
+
+        let mut context = RenderContext::new();
+        context.no_crate_header = true;
+        context.metadata = Some(crate::renderer::CrateMetadata {
+            license: Some("MIT".to_string()),
+            repository: None,
+            authors: vec![],
+        });
+
+        let output = ParsedRenderer.render(&module, Some("0.1.0"), &context);
+        assert!(!output.contains("# Crate:"));
+        assert!(!output.contains("Version:") && !output.contains("License:"));
+        assert!(!output.contains("#!["));
+        assert!(output.starts_with("  pub struct Config"));
+    }
+
+    #[test]
+    fn test_trait_assoc_type_default_and_bounds() {
+        let mut counter = marker_trait();
+        counter["trait"]["items"] = json!([2, 3]);
+        let assoc_type = |bounds: Value, default: Value| json!({"assoc_type": {
+            "generics": empty_generics(),
+            "bounds": bounds,
+            "type": default
+        }});
+        let copy_bound = json!([{"trait_bound": {
+            "trait": {"path": "Copy", "id": 91, "args": null},
+            "generic_params": [],
+            "modifier": "none"
+        }}]);
+        let crate_data = build_crate(
+            &[1],
+            vec![
+                item(1, "Counter", counter),
+                item(2, "Step", assoc_type(copy_bound, json!({"primitive": "u32"}))),
+                item(3, "Label", assoc_type(json!([]), Value::Null)),
+            ],
+        );
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("    type Step: Copy = u32\n"));
+        assert!(output.contains("    type Label\n"));
+    }
+
+    #[test]
+    fn test_inherent_impl_docs_render_above_header() {
+        let mut config = plain_struct(&[]);
+        config["struct"]["impls"] = json!([2]);
+        let mut impl_block = auto_trait_impl("", 1, false);
+        impl_block["impl"]["trait"] = Value::Null;
+        impl_block["impl"]["for"]["resolved_path"]["path"] = json!("Config");
+        impl_block["impl"]["is_synthetic"] = json!(false);
+        impl_block["impl"]["items"] = json!([3]);
+        let mut constructors = item(2, "", impl_block);
+        constructors["docs"] = json!("Constructors");
+        let crate_data = build_crate(
+            &[1],
+            vec![
+                item(1, "Config", config),
+                constructors,
+                item(3, "new", function(json!([]), Value::Null, json!({}))),
+            ],
+        );
+        let module = parse(&crate_data);
+
+        let mut context = RenderContext::new();
+        context.show_impl_headers = true;
+        let output = render_items(&module, &context);
+        assert!(output.contains("  /// Constructors\n  impl Config {\n    pub fn new()\n  }\n"));
+
+        let flattened = render_items(&module, &RenderContext::new());
+        assert!(!flattened.contains("Constructors"));
+    }
+
+    #[test]
+    fn test_crlf_docs_render_clean() {
+        let mut person = item(1, "Person", plain_struct(&[]));
+        person["docs"] = json!("A simple person struct\r\n\r\nHolds a name and age\rand nothing else\r");
+        let output = render_items(&parse(&build_crate(&[1], vec![person])), &RenderContext::new());
+        assert!(!output.contains('\r'));
+        assert!(output.starts_with(
+            "  /// A simple person struct\n  ///\n  /// Holds a name and age\n  /// and nothing else\n  pub struct Person"
+        ));
+    }
+
+    #[test]
+    fn test_const_and_static_keep_their_keywords() {
+        // Mirrors the attributes fixture's `X86_64_SPECIFIC` const and `SPECIAL_DATA` static
+        let constant = item(1, "X86_64_SPECIFIC", json!({"constant": {
+            "type": {"primitive": "usize"},
+            "const": {"expr": "64", "value": null, "is_literal": true}
+        }}));
+        let static_item = item(2, "SPECIAL_DATA", json!({"static": {
+            "type": {"primitive": "i32"},
+            "is_mutable": false,
+            "is_unsafe": false,
+            "expr": "42"
+        }}));
+        let module = parse(&build_crate(&[1, 2], vec![constant, static_item]));
+        assert!(matches!(module.items[0], ParsedItem::Constant(_)));
+        assert!(matches!(module.items[1], ParsedItem::Static(_)));
+
+        let output = render_items(&module, &RenderContext::new());
+        assert!(output.contains("  pub const X86_64_SPECIFIC: usize = 64\n"));
+        assert!(output.contains("  pub static SPECIAL_DATA: i32 = 42\n"));
+    }
+
+    #[test]
+    fn test_type_alias_renders_dyn_fn_target() {
+        // Shaped like the async_await fixture's `AsyncClosure<T>`, with a parenthesized `Fn` argument
+        let poly_trait = |path: &str, args: Value| json!({"trait": {"path": path, "id": 95, "args": args}, "generic_params": []});
+        let fn_args = json!({"parenthesized": {
+            "inputs": [{"borrowed_ref": {"lifetime": null, "is_mutable": false, "type": {"primitive": "str"}}}],
+            "output": {"primitive": "bool"}
+        }});
+        let boxed_fn = json!({"resolved_path": {
+            "path": "Box",
+            "id": 96,
+            "args": {"angle_bracketed": {"args": [{"type": {"dyn_trait": {
+                "traits": [poly_trait("Fn", fn_args), poly_trait("Send", Value::Null)],
+                "lifetime": null
+            }}}], "constraints": []}}
+        }});
+        let mut alias = item(1, "Predicate", json!({"type_alias": {
+            "type": boxed_fn,
+            "generics": {
+                "params": [{"name": "T", "kind": {"type": {"bounds": [], "default": null, "is_synthetic": false}}}],
+                "where_predicates": []
+            }
+        }}));
+        alias["docs"] = json!("A boxed predicate");
+        let module = parse(&build_crate(&[1], vec![alias]));
+        assert!(matches!(module.items[0], ParsedItem::TypeAlias(_)));
+
+        let output = render_items(&module, &RenderContext::new());
+        assert!(output.contains("  /// A boxed predicate\n  pub type Predicate<T> = Box<dyn Fn(&str) -> bool + Send>\n"));
+    }
+
+    #[test]
+    fn test_fn_family_generic_bound_uses_parentheses() {
+        let fn_mut = json!({"trait_bound": {
+            "trait": {"path": "FnMut", "id": 97, "args": {"parenthesized": {
+                "inputs": [{"borrowed_ref": {"lifetime": null, "is_mutable": false, "type": {"primitive": "str"}}}],
+                "output": null
+            }}},
+            "generic_params": [],
+            "modifier": "none"
+        }});
+        let mut for_each_line = function(json!([["f", {"generic": "F"}]]), Value::Null, json!({}));
+        for_each_line["function"]["generics"]["params"] = json!([{"name": "F", "kind": {"type": {
+            "bounds": [fn_mut], "default": null, "is_synthetic": false
+        }}}]);
+        let crate_data = build_crate(&[1], vec![item(1, "for_each_line", for_each_line)]);
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("  pub fn for_each_line<F: FnMut(&str)>(f: F)\n"));
+    }
+
+    #[test]
+    fn test_render_split_pages_per_top_level_module() {
+        let storage = item(1, "storage", json!({"module": {"is_crate": false, "items": [2], "is_stripped": false}}));
+        let crate_data = build_crate(
+            &[1, 3],
+            vec![
+                storage,
+                item(2, "Storage", plain_struct(&[])),
+                item(3, "connect", function(json!([]), Value::Null, json!({}))),
+            ],
+        );
+
+        let pages = ParsedRenderer.render_split(&parse(&crate_data), None, &RenderContext::new());
+        let names: Vec<&str> = pages.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["index.txt", "storage.txt"]);
+        assert!(pages[0].1.starts_with("# Crate: test_crate\n"));
+        assert!(pages[0].1.contains("  pub fn connect()\n"));
+        assert!(pages[0].1.ends_with("  pub mod storage // storage.txt\n"));
+        assert!(!pages[0].1.contains("pub struct Storage"));
+        assert!(pages[1].1.starts_with("pub mod storage\n\n  pub struct Storage {"));
+    }
+
+    #[test]
+    fn test_focused_module_renders_without_mod_wrapper() {
+        // Mirrors `network::protocol` in the complex fixture
+        let module = |id: u32, name: &str, items: &[u32]| {
+            item(id, name, json!({"module": {"is_crate": false, "items": items, "is_stripped": false}}))
+        };
+        let mut crate_data = build_crate(
+            &[1],
+            vec![
+                module(1, "network", &[2]),
+                module(2, "protocol", &[3]),
+                item(3, "Protocol", marker_trait()),
+            ],
+        );
+
+        crate::filter_by_path(&mut crate_data, "network::protocol").unwrap();
+        let output = ParsedRenderer.render(&parse(&crate_data), None, &RenderContext::new());
+        assert!(output.starts_with("# Crate: protocol\n\n  pub trait Protocol {"));
+        assert!(!output.contains("mod protocol"));
+        assert!(!output.contains("mod network"));
+    }
+
+    #[test]
+    fn test_lifetime_params_render_first() {
+        let type_param = |name: &str| json!({"name": name, "kind": {"type": {"bounds": [], "default": null, "is_synthetic": false}}});
+        let mut cache = plain_struct(&[]);
+        cache["struct"]["generics"]["params"] = json!([
+            type_param("T"),
+            {"name": "'a", "kind": {"lifetime": {"outlives": []}}},
+            {"name": "N", "kind": {"const": {"type": {"primitive": "usize"}, "default": null}}},
+            type_param("U")
+        ]);
+        let output = render_items(&parse(&build_crate(&[1], vec![item(1, "Cache", cache)])), &RenderContext::new());
+        assert!(output.contains("  pub struct Cache<'a, T, const N: usize, U> {"));
+    }
+
+    #[test]
+    fn test_cfg_test_items_hidden_unless_included() {
+        // Shaped like the trait_objects fixture's `#[cfg(test)] mod tests`, as rustdoc records it under `--cfg test`
+        let mut tests = item(1, "tests", json!({"module": {"is_crate": false, "items": [3], "is_stripped": false}}));
+        tests["attrs"] = json!(["#[cfg(test)]"]);
+        let mut helper = item(2, "test_helper", function(json!([]), Value::Null, json!({})));
+        helper["attrs"] =
+            json!([r#"#[attr = CfgTrace([NameValue { name: "test", value: None, span: src/lib.rs:1:7: 1:11 (#0) }])]"#]);
+        let crate_data = build_crate(
+            &[1, 2, 4],
+            vec![
+                tests,
+                helper,
+                item(3, "test_trait_objects", function(json!([]), Value::Null, json!({}))),
+                item(4, "draw_all", function(json!([]), Value::Null, json!({}))),
+            ],
+        );
+
+        let default = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(default.contains("pub fn draw_all()"));
+        assert!(!default.contains("mod tests") && !default.contains("test_helper"));
+
+        let module = ItemParser::new(&crate_data).with_test_items(true).parse_crate().unwrap();
+        let output = render_items(&module, &RenderContext::new());
+        assert!(output.contains("pub mod tests"));
+        assert!(output.contains("pub fn test_trait_objects()"));
+        assert!(output.contains("pub fn test_helper()"));
+    }
+
+    #[test]
+    fn test_single_element_tuple_keeps_trailing_comma() {
+        let wrap = function(
+            json!([["value", {"tuple": [{"primitive": "u32"}]}]]),
+            json!({"tuple": [{"tuple": [{"primitive": "u8"}]}, {"tuple": []}]}),
+            json!({}),
+        );
+        let crate_data = build_crate(&[1], vec![item(1, "wrap", wrap)]);
+
+        let output = render_items(&parse(&crate_data), &RenderContext::new());
+        assert!(output.contains("  pub fn wrap(value: (u32,)) -> ((u8,), ())\n"));
+    }
+
+    #[test]
+    fn test_merge_targets_marks_items_missing_from_some_targets() {
+        // The attributes fixture's cfg-gated functions, as the unix and windows
+        // builds each see them
+        let platform_crate = |specific: &str| {
+            let mut sys = item(3, "sys", json!({"module": {"is_crate": false, "items": [4], "is_stripped": false}}));
+            sys["docs"] = json!("Platform glue");
+            build_crate(
+                &[1, 2, 3],
+                vec![
+                    item(1, "Platform", plain_struct(&[])),
+                    item(2, &format!("{}_specific_function", specific), function(json!([]), json!({"primitive": "i32"}), json!({}))),
+                    sys,
+                    item(4, &format!("{}_handle", specific), function(json!([]), json!(null), json!({}))),
+                ],
+            )
+        };
+        let builds = vec![
+            ("x86_64-unknown-linux-gnu".to_string(), parse(&platform_crate("unix"))),
+            ("x86_64-pc-windows-msvc".to_string(), parse(&platform_crate("windows"))),
+        ];
+        let merged = merge_targets(builds).expect("two builds merge into one crate");
+
+        let output = render_items(&merged, &RenderContext::new());
+        assert_eq!(output.matches("pub struct Platform").count(), 1);
+        assert!(!output.contains("// only on: x86_64-unknown-linux-gnu, x86_64-pc-windows-msvc"));
+        assert!(output.contains(
+            "  // only on: x86_64-unknown-linux-gnu\n  pub fn unix_specific_function() -> i32\n"
+        ));
+        assert!(output.contains(
+            "  // only on: x86_64-pc-windows-msvc\n  pub fn windows_specific_function() -> i32\n"
+        ));
+        // The shared module is merged rather than repeated, and annotates its own children
+        assert_eq!(output.matches("pub mod sys").count(), 1);
+        assert!(output.contains("    // only on: x86_64-unknown-linux-gnu\n    pub fn unix_handle()\n"));
+        assert!(output.contains("    // only on: x86_64-pc-windows-msvc\n    pub fn windows_handle()\n"));
+
+        assert_eq!(CrateStats::collect(&merged).functions, 4);
+
+        let mut context = RenderContext::new();
+        context.no_auto_comment = true;
+        let bare = render_items(&merged, &context);
+        assert!(!bare.contains("// only on:"));
+        assert!(bare.contains("  pub fn windows_specific_function() -> i32\n"));
+    }
+
+    #[test]
+    fn test_missing_root_is_a_parse_error() {
+        let mut crate_data = build_crate(&[], vec![]);
+        crate_data.index.clear();
+
+        let err = ItemParser::new(&crate_data).parse_crate().unwrap_err();
+        assert!(matches!(err, crate::error::DoccerError::Parse(_)));
+        assert_eq!(err.to_string(), "Root module not found");
+    }
+}
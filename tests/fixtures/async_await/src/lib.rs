@@ -141,6 +141,9 @@ pub struct AsyncIterator {
 }
 
 impl AsyncIterator {
+    /// Number of items yielded when no other bound is given
+    pub const DEFAULT_MAX: usize = 10;
+
     pub fn new(max: usize) -> Self {
         Self { current: 0, max }
     }
@@ -2,6 +2,8 @@
 //! This module tests doccer's ability to parse and display various compiler
 //! attributes that provide important metadata about API usage and behavior.
 
+#![deny(unsafe_op_in_unsafe_fn)]
+
 use std::fmt::Display;
 
 // =============================================================================
@@ -26,6 +28,19 @@ pub fn windows_specific_function() -> i32 {
     84
 }
 
+/// Describes the platform the crate was built for
+pub struct Platform {
+    pub name: &'static str,
+}
+
+/// Only Unix builds get a default platform
+#[cfg(unix)]
+impl Default for Platform {
+    fn default() -> Self {
+        Platform { name: "unix" }
+    }
+}
+
 /// Available only in debug builds
 #[cfg(debug_assertions)]
 pub fn debug_only_function() {
@@ -85,6 +100,13 @@ pub fn error_handler() -> ! {
     panic!("Critical error occurred");
 }
 
+/// A function whose panics point at the caller
+#[track_caller]
+pub fn expect_positive(x: i32) -> i32 {
+    assert!(x > 0, "expected a positive number");
+    x
+}
+
 /// A function optimized for size rather than speed
 #[cfg(feature = "optimize")]
 pub fn size_optimized_function() -> Vec<u8> {
@@ -216,6 +238,13 @@ pub fn documented_function(x: i32) -> i32 {
     x * 2
 }
 
+/// A function findable under alternate names
+#[doc(alias = "twice")]
+#[doc(alias("double", "times_two"))]
+pub fn aliased_function(x: i32) -> i32 {
+    x * 2
+}
+
 /// A function that is hidden from documentation
 #[doc(hidden)]
 pub fn internal_function() -> i32 {
@@ -13,6 +13,13 @@ pub struct Person {
 
 impl Person {
     /// Creates a new person
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let person = basic_types::Person::new("Ada".to_string(), 36);
+    /// assert_eq!(person.get_name(), "Ada");
+    /// ```
     pub fn new(name: String, age: u32) -> Self {
         Self { name, age }
     }
@@ -30,7 +37,10 @@ pub enum Vehicle {
     /// A bicycle
     Bike,
     /// A truck with cargo capacity in tons
-    Truck { capacity: f32 },
+    Truck {
+        /// Maximum load in tons
+        capacity: f32,
+    },
 }
 
 /// A simple constant
@@ -59,6 +59,12 @@ pub enum HttpStatus {
     NotFound,
     /// 500 Internal Server Error
     InternalError,
+    /// 418 I'm a teapot
+    #[deprecated(since = "1.4.0", note = "Not a real status")]
+    Teapot,
+    /// Any other status code
+    #[non_exhaustive]
+    Other { code: u16 },
 }
 
 /// A trait for handling deprecation
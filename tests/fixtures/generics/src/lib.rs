@@ -36,6 +36,20 @@ pub struct Pair<T, U> {
     pub second: U,
 }
 
+/// One of two values, each with its own bound
+pub enum Either<L: Display, R: std::fmt::Debug> {
+    /// The left value
+    Left(L),
+    /// The right value
+    Right(R),
+}
+
+/// A wrapper whose element type defaults to `u32`
+pub struct Wrapper<T = u32> {
+    /// The wrapped value
+    pub value: T,
+}
+
 /// A trait for types that can be compared
 pub trait Comparable<T> {
     /// Compare this value with another
@@ -112,3 +126,31 @@ pub trait Constants<T> {
     /// Maximum value
     const MAX: T;
 }
+
+/// Trait whose methods take `self` by value
+pub trait Builder
+where
+    Self: Sized,
+{
+    /// Consume the builder
+    fn build(self) -> Self;
+}
+
+/// Const generic function returning a fixed-size buffer
+pub fn make<const N: usize>() -> [u8; N] {
+    [0; N]
+}
+
+/// Trait with a defaulted type parameter, like `std::ops::Add`
+pub trait Combine<Rhs = Self> {
+    /// The combined result
+    type Output;
+
+    /// Combine with another value
+    fn combine(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Generic function accepting unsized types such as `str` or `[T]`
+pub fn size_of_ref<T: ?Sized>(value: &T) -> usize {
+    std::mem::size_of_val(value)
+}
@@ -44,6 +44,11 @@ pub mod utils {
             // This module is private, so this won't be visible
         }
     }
+
+    /// Trims surrounding whitespace, declared after the nested modules
+    pub fn trim(input: &str) -> &str {
+        input.trim()
+    }
 }
 
 /// Network-related functionality
@@ -243,6 +243,37 @@ pub fn use_higher_ranked_trait_object(
     f
 }
 
+/// Closure parameter written with `impl Trait`
+pub fn apply_twice(f: impl Fn(i32) -> i32, x: i32) -> i32 {
+    f(f(x))
+}
+
+/// Closure parameter bound through a generic
+pub fn retain_matching<F: FnMut(&str) -> bool>(items: &mut Vec<String>, mut keep: F) {
+    items.retain(|item| keep(item));
+}
+
+mod private {
+    /// Supertrait that cannot be named outside this crate
+    pub trait Sealed {}
+
+    impl Sealed for super::Circle {}
+}
+
+/// A trait that only this crate can implement
+///
+/// The private `Sealed` supertrait prevents downstream implementations.
+pub trait SealedShape: private::Sealed {
+    /// Number of sides of the shape
+    fn sides(&self) -> u32;
+}
+
+impl SealedShape for Circle {
+    fn sides(&self) -> u32 {
+        0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
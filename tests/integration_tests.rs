@@ -1,10 +1,16 @@
-use std::path::Path;
-use std::process::Command;
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
 
 mod snapshots;
 
 /// Test doccer against a fixture by running it and returning the output
 fn run_doccer_on_fixture(fixture_name: &str) -> String {
+    run_doccer_on_fixture_with_args(fixture_name, &[])
+}
+
+/// Like `run_doccer_on_fixture`, passing extra command-line arguments to doccer
+fn run_doccer_on_fixture_with_args(fixture_name: &str, extra_args: &[&str]) -> String {
     // Path to the fixture crate
     let fixture_crate_path = format!("tests/fixtures/{}", fixture_name);
 
@@ -17,7 +23,7 @@ fn run_doccer_on_fixture(fixture_name: &str) -> String {
 
     // Build the doccer binary first
     let build_output = Command::new("cargo")
-        .args(&["build", "--bin", "doccer"])
+        .args(["build", "--bin", "doccer"])
         .output()
         .expect("Failed to build doccer");
 
@@ -30,7 +36,7 @@ fn run_doccer_on_fixture(fixture_name: &str) -> String {
 
     // Run doccer on the fixture
     let output = Command::new("cargo")
-        .args(&[
+        .args([
             "run",
             "--bin",
             "doccer",
@@ -38,6 +44,7 @@ fn run_doccer_on_fixture(fixture_name: &str) -> String {
             "--crate-path",
             &fixture_crate_path,
         ])
+        .args(extra_args)
         .output()
         .expect("Failed to run doccer");
 
@@ -52,6 +59,42 @@ fn run_doccer_on_fixture(fixture_name: &str) -> String {
     String::from_utf8(output.stdout).expect("Invalid UTF-8 in doccer output")
 }
 
+/// Write a rustdoc JSON crate named `name` whose root module holds a single function
+/// `fn_name`, with `function` as its `inner.function` payload. Returns the file's path.
+fn write_single_fn_crate(name: &str, fn_name: &str, function: Value) -> PathBuf {
+    let item = |id: u32, name: &str, inner: Value| {
+        json!({
+            "id": id, "crate_id": 0, "name": name, "span": null, "visibility": "public",
+            "docs": null, "links": {}, "attrs": [], "deprecation": null, "inner": inner
+        })
+    };
+    let crate_json = json!({
+        "root": 0,
+        "crate_version": "0.1.0",
+        "includes_private": false,
+        "index": {
+            "0": item(0, name, json!({"module": {"is_crate": true, "items": [1], "is_stripped": false}})),
+            "1": item(1, fn_name, json!({"function": function}))
+        },
+        "paths": {},
+        "external_crates": {},
+        "target": {"triple": "x86_64-unknown-linux-gnu", "target_features": []},
+        "format_version": 53
+    });
+    let json_path = std::env::temp_dir().join(format!("doccer_{}.json", name));
+    std::fs::write(&json_path, crate_json.to_string()).unwrap();
+    json_path
+}
+
+/// Run the doccer binary on a JSON file with extra command-line arguments
+fn run_doccer_on_json(json_path: &Path, extra_args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_doccer"))
+        .arg(json_path)
+        .args(extra_args)
+        .output()
+        .expect("Failed to run doccer")
+}
+
 #[test]
 fn test_basic_types_fixture() {
     let _settings = snapshots::configure_insta();
@@ -113,4 +156,247 @@ fn test_attributes_fixture() {
     let _settings = snapshots::configure_insta();
     let output = run_doccer_on_fixture("attributes");
     insta::assert_snapshot!(output);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_complex_fixture_stats() {
+    let output = run_doccer_on_fixture_with_args("complex", &["--stats"]);
+    assert_eq!(
+        output.trim(),
+        "modules: 6, structs: 9, enums: 0, traits: 2, functions: 0, type_aliases: 0, constants: 3, statics: 0, macros: 1 (public: 21, private: 0)"
+    );
+}
+
+#[test]
+fn test_diagnostics_stay_off_stdout() {
+    let fixture_crate_path = "tests/fixtures/basic_types";
+    let output = Command::new("cargo")
+        .args(["run", "--bin", "doccer", "--", "--crate-path", fixture_crate_path])
+        .env("RUST_LOG", "debug")
+        .output()
+        .expect("Failed to run doccer");
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).expect("Invalid UTF-8 in doccer output");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    // Rendered docs on stdout, logs on stderr
+    assert!(stdout.starts_with("# Crate: basic_types\n"));
+    assert!(!stdout.contains("DEBUG") && !stdout.contains(" INFO "));
+    assert!(stderr.contains("INFO"));
+}
+
+#[test]
+fn test_modules_fixture_exclude() {
+    let output = run_doccer_on_fixture_with_args(
+        "modules",
+        &["--include-private", "--exclude", "*_helper"],
+    );
+    assert!(!output.contains("internal_helper"));
+    assert!(!output.contains("private_helper"));
+    // `*_helper` needs the underscore, so plain `helper` stays
+    assert!(output.contains("pub fn helper() -> String"));
+    assert!(output.contains("pub fn deep_function() -> bool"));
+}
+
+#[test]
+fn test_modules_fixture_exclude_within_item_path() {
+    let output = run_doccer_on_fixture_with_args(
+        "modules",
+        &["modules::utils", "--include-private", "--exclude", "*_helper"],
+    );
+    assert!(output.contains("pub fn trim(input: &str) -> &str"));
+    assert!(!output.contains("internal_helper"));
+    assert!(!output.contains("private_helper"));
+}
+
+#[test]
+fn test_modules_fixture_exclude_wins_over_item_path() {
+    let output = run_doccer_on_fixture_with_args(
+        "modules",
+        &[
+            "modules::utils::internal_helper",
+            "--include-private",
+            "--exclude",
+            "*_helper",
+        ],
+    );
+    assert!(!output.contains("internal_helper"));
+}
+
+#[test]
+fn test_basic_types_fixture_check() {
+    let output = run_doccer_on_fixture_with_args("basic_types", &["--check"]);
+    assert!(output.is_empty(), "--check should not render anything");
+}
+
+#[test]
+fn test_check_rejects_malformed_json() {
+    let json_path = std::env::temp_dir().join("doccer_check_malformed.json");
+    std::fs::write(&json_path, r#"{"root": 0, "index": {"#).unwrap();
+
+    let output = run_doccer_on_json(&json_path, &["--check"]);
+    let _ = std::fs::remove_file(&json_path);
+
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(!output.stderr.is_empty(), "a failed check should explain itself");
+}
+
+#[test]
+fn test_fail_on_unknown_reports_unsupported_types() {
+    // `clamp(value: u32 is 1..)`: a pattern type, which doccer has no rendering for
+    let json_path = write_single_fn_crate(
+        "patterns",
+        "clamp",
+        json!({
+            "sig": {"inputs": [["value", {"pat": {"type": {"primitive": "u32"},
+                                                   "__pat_unstable_do_not_use": "1.."}}]],
+                    "output": null, "is_c_variadic": false},
+            "generics": {"params": [], "where_predicates": []},
+            "header": {"is_const": false, "is_unsafe": false, "is_async": false, "abi": "Rust"},
+            "has_body": true
+        }),
+    );
+
+    let lenient = run_doccer_on_json(&json_path, &[]);
+    let strict = run_doccer_on_json(&json_path, &["--fail-on-unknown"]);
+    let _ = std::fs::remove_file(&json_path);
+
+    assert!(lenient.status.success());
+    assert!(String::from_utf8_lossy(&lenient.stdout).contains("pub fn clamp(value: ...)"));
+
+    assert!(!strict.status.success());
+    assert!(strict.stdout.is_empty());
+    let stderr = String::from_utf8_lossy(&strict.stderr);
+    assert!(stderr.contains("1 unsupported type(s) in crate 'patterns'"));
+    assert!(stderr.contains("in `clamp`: {\"pat\":"));
+}
+
+#[test]
+fn test_attributes_fixture_track_caller_with_perf_attrs() {
+    let output = run_doccer_on_fixture_with_args("attributes", &["--show-perf-attrs"]);
+    assert!(output.contains("  #[track_caller]\n  pub fn expect_positive(x: i32) -> i32\n"));
+
+    let default = run_doccer_on_fixture("attributes");
+    assert!(default.contains("pub fn expect_positive(x: i32) -> i32"));
+    assert!(!default.contains("#[track_caller]"));
+}
+
+#[test]
+fn test_generics_fixture_enum_param_bounds() {
+    let output = run_doccer_on_fixture("generics");
+    assert!(output.contains("  pub enum Either<L: Display, R: std::fmt::Debug> {\n"));
+}
+
+#[test]
+fn test_dump_parsed_prints_item_tree() {
+    let json_path = write_single_fn_crate(
+        "dumped",
+        "ping",
+        json!({
+            "sig": {"inputs": [], "output": {"primitive": "bool"}, "is_c_variadic": false},
+            "generics": {"params": [], "where_predicates": []},
+            "header": {"is_const": false, "is_unsafe": false, "is_async": false, "abi": "Rust"},
+            "has_body": true
+        }),
+    );
+
+    let output = run_doccer_on_json(&json_path, &["--dump-parsed"]);
+    let _ = std::fs::remove_file(&json_path);
+
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("ParsedModule {"));
+    assert!(stderr.contains("name: \"dumped\""));
+    assert!(stderr.contains("name: \"ping\""));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("pub fn ping() -> bool"));
+}
+
+#[test]
+fn test_complex_fixture_output_dir() {
+    let output_dir = std::env::temp_dir().join("doccer_complex_output_dir");
+    let _ = std::fs::remove_dir_all(&output_dir);
+    let output = run_doccer_on_fixture_with_args("complex", &["--output-dir", output_dir.to_str().unwrap()]);
+    assert!(output.is_empty(), "--output-dir should not print the docs");
+
+    let read = |name: &str| std::fs::read_to_string(output_dir.join(name)).unwrap();
+    let index = read("index.txt");
+    assert!(index.starts_with("# Crate: complex\n"));
+    assert!(index.contains("  pub mod storage // storage.txt\n"));
+    for module in ["storage", "network", "math"] {
+        assert!(read(&format!("{}.txt", module)).starts_with(&format!("pub mod {}\n", module)));
+    }
+    let _ = std::fs::remove_dir_all(&output_dir);
+}
+
+#[test]
+fn test_complex_fixture_crate_docs_use_inner_prefix() {
+    let output = run_doccer_on_fixture("complex");
+    assert!(output.contains(
+        "Version: 0.1.0\n\n//! Complex fixture for testing doccer\n//!\n//! This crate combines all Rust features"
+    ));
+}
+
+#[test]
+fn test_modules_fixture_include_private() {
+    let output = run_doccer_on_fixture_with_args("modules", &["--include-private"]);
+    assert!(output.contains("    pub(crate) fn internal_helper() -> i32\n"));
+    assert!(output.contains("    pub(in crate::utils) fn private_helper()\n"));
+
+    let public_only = run_doccer_on_fixture("modules");
+    assert!(!public_only.contains("private_helper"));
+}
+
+#[test]
+fn test_basic_types_fixture_max_items() {
+    let output = run_doccer_on_fixture_with_args("basic_types", &["--max-items", "2"]);
+    assert!(output.contains("pub struct Person"));
+    assert!(output.contains("pub enum Vehicle"));
+    assert!(!output.contains("MAX_USERS"));
+    assert!(output.contains("// ... 3 more items (pass an item path or use --exclude to narrow)"));
+}
+
+#[test]
+fn test_basic_types_fixture_metadata() {
+    let output = run_doccer_on_fixture_with_args("basic_types", &["--metadata"]);
+    assert!(output.contains("Version: 0.1.0\nLicense: MIT OR Apache-2.0\n"));
+    assert!(output.contains("Repository: https://github.com/example/basic_types\n"));
+}
+
+#[test]
+fn test_modules_fixture_breadth_first() {
+    let output = run_doccer_on_fixture_with_args("modules", &["--breadth-first"]);
+    let trim = output.find("pub fn trim(input: &str) -> &str").unwrap();
+    let nested = output.find("pub mod nested").unwrap();
+    assert!(trim < nested, "functions should render before the nested module");
+}
+
+#[test]
+fn test_trait_objects_fixture_show_inherited() {
+    let output = run_doccer_on_fixture_with_args("trait_objects", &["--show-inherited"]);
+    assert!(output.contains(
+        "  impl Clickable for Circle {\n\n    fn on_click(&mut self)\n\n    // Provided by the trait\n    /// Check if the object is clickable\n    fn is_clickable(&self) -> bool\n  }"
+    ));
+
+    let output = run_doccer_on_fixture("trait_objects");
+    assert!(!output.contains("// Provided by the trait"));
+}
+
+#[test]
+fn test_deprecation_fixture_since() {
+    let output = run_doccer_on_fixture_with_args("deprecation", &["--since", "1.2.0"]);
+    assert!(output.contains(
+        "  CHANGED since 1.2.0 (deprecated in 1.3.0)\n  DEPRECATED since 1.3.0\n  /// A deprecated enum"
+    ));
+    assert!(!output.contains("deprecated in 1.1.0"));
+    assert!(!output.contains("NEW since"));
+}
+
+#[test]
+fn test_complex_fixture_show_impl_headers() {
+    let output = run_doccer_on_fixture_with_args("complex", &["--show-impl-headers"]);
+    assert!(output.contains(
+        "    impl<K, V> Storage<K, V>\n    where\n        K: Clone + Debug + PartialEq + Eq + std::hash::Hash,\n        V: Clone + Debug,\n    {\n"
+    ));
+}